@@ -0,0 +1,343 @@
+//! Expanding the global attributes of a source file with the proc macro implementations to find the
+//! `_plugin_entry!` invocations they generate
+
+use crate::Error;
+use crate::modules::{child_dir, module_file};
+use bevy_auto_plugin_shared::__private::attribute_args::attributes::modes::global::auto_generics_set::AutoGenericsSetArgs;
+use bevy_auto_plugin_shared::__private::attribute_args::attributes::modes::global::auto_preset::{
+    AutoPresetArgs, AutoPresetExpandArgs,
+};
+use bevy_auto_plugin_shared::__private::modes::global::inner::{
+    expand_global_auto_plugin_entry, expand_global_derive_global_auto_plugin,
+    global_attribute_expander,
+};
+use bevy_auto_plugin_shared::__private::util::extensions::item::ItemAttrsExt;
+use bevy_auto_plugin_shared::__private::util::{crate_path, debug_expand};
+use darling::ast::NestedMeta;
+use proc_macro2::{Delimiter, TokenStream as MacroStream, TokenTree};
+use quote::{ToTokens, quote};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use syn::{AttrStyle, Attribute, Item, ItemMacro, ItemMod, Meta, Stmt};
+
+/// A registry entry, identified by its module path and static ident
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    /// `crate::<module path>::<static ident>`
+    pub id_path: String,
+    /// `cfg`s of the item and its enclosing modules
+    pub cfgs: Vec<Attribute>,
+}
+
+/// Where an item is declared
+#[derive(Debug, Clone)]
+pub(crate) struct Scope {
+    /// `module_path!()` segments, starting with `crate`
+    pub module: Vec<String>,
+    pub cfgs: Vec<Attribute>,
+    /// File the item is in
+    pub file: PathBuf,
+    /// Directory the files of child modules are in
+    pub dir: PathBuf,
+    /// Inside an inline `mod foo { .. }`
+    pub inline: bool,
+    /// Inside a fn body, where `module_path!()` is still the enclosing module's
+    pub in_fn: bool,
+}
+
+impl Scope {
+    pub fn root(file: PathBuf, dir: PathBuf) -> Self {
+        Self {
+            module: vec!["crate".to_string()],
+            cfgs: Vec::new(),
+            file,
+            dir,
+            inline: false,
+            in_fn: false,
+        }
+    }
+
+    pub fn child_module(&self, name: &str) -> String {
+        format!("{}::{name}", self.module.join("::"))
+    }
+
+    fn with_cfgs(&self, attrs: &[Attribute]) -> Self {
+        let mut scope = self.clone();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("cfg")) {
+            let cfg = attr.meta.to_token_stream().to_string();
+            if !scope
+                .cfgs
+                .iter()
+                .any(|existing| existing.meta.to_token_stream().to_string() == cfg)
+            {
+                // `#![cfg(..)]` at the top of a module file applies to the whole module
+                let mut attr = attr.clone();
+                attr.style = AttrStyle::Outer;
+                scope.cfgs.push(attr);
+            }
+        }
+        scope
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct EntryCollector {
+    pub entries: Vec<Entry>,
+    /// Source files read so far
+    pub files: Vec<PathBuf>,
+    /// `auto_preset!`s seen so far by name
+    presets: HashMap<String, AutoPresetArgs>,
+    /// `auto_generics_set!`s seen so far by name
+    generics_sets: HashMap<String, AutoGenericsSetArgs>,
+    /// Macro invocations shaped like an applied preset or generics set that wasn't seen yet
+    pending: Vec<(ItemMacro, Scope)>,
+}
+
+impl EntryCollector {
+    pub fn visit_file(&mut self, file: &Path, scope: &Scope) -> Result<(), Error> {
+        let source = std::fs::read_to_string(file).map_err(|source| Error::Read {
+            path: file.to_path_buf(),
+            source,
+        })?;
+        self.files.push(file.to_path_buf());
+        let parsed = syn::parse_file(&source).map_err(|source| Error::Parse {
+            path: file.to_path_buf(),
+            source,
+        })?;
+        let scope = scope.with_cfgs(&parsed.attrs);
+        parsed
+            .items
+            .into_iter()
+            .try_for_each(|item| self.visit_item(item, &scope))
+    }
+
+    /// Expands the item like the compiler would: the first global attribute, then the
+    /// `AutoPlugin` derive, recursing into whatever they expand to
+    fn visit_item(&mut self, mut item: Item, scope: &Scope) -> Result<(), Error> {
+        let attrs = item
+            .attrs_mut()
+            .map(|attrs| attrs.clone())
+            .unwrap_or_default();
+        let scope = &scope.with_cfgs(&attrs);
+        if let Some((ix, name, expand)) = find_global_attribute(&item, &attrs) {
+            let Ok(attrs) = item.attrs_mut() else {
+                unreachable!("found the attribute on the item")
+            };
+            let args = match attrs.remove(ix).meta {
+                Meta::List(list) => list.tokens,
+                _ => MacroStream::new(),
+            };
+            let (args, _) = debug_expand::take_debug_expand_flag(args);
+            let output = crate_path::expand_attribute(args, item.to_token_stream(), expand);
+            return self.visit_expansion(&name, output, scope);
+        }
+        if derives_auto_plugin(&attrs) {
+            if let Ok(attrs) = item.attrs_mut() {
+                debug_expand::take_debug_expand_flag_from_attrs(attrs, "auto_plugin");
+            }
+            let output = crate_path::expand_derive(
+                "auto_plugin",
+                item.to_token_stream(),
+                expand_global_derive_global_auto_plugin,
+            );
+            return self.visit_expansion("derive(AutoPlugin)", output, scope);
+        }
+        match item {
+            Item::Macro(item) if is_last_segment(&item.mac.path, "_plugin_entry") => {
+                if let Some(TokenTree::Ident(ident)) = item.mac.tokens.into_iter().next() {
+                    self.entries.push(Entry {
+                        id_path: format!("{}::{ident}", scope.module.join("::")),
+                        cfgs: scope.cfgs.clone(),
+                    });
+                }
+                Ok(())
+            }
+            Item::Macro(item) if is_last_segment(&item.mac.path, "auto_plugin_entry") => {
+                let (tokens, _) = debug_expand::take_debug_expand_flag(item.mac.tokens);
+                let output = crate_path::expand_fn_like(tokens, expand_global_auto_plugin_entry);
+                self.visit_expansion("auto_plugin_entry", output, scope)
+            }
+            Item::Macro(item) if is_last_segment(&item.mac.path, "auto_preset") => {
+                let (tokens, _) = debug_expand::take_debug_expand_flag(item.mac.tokens);
+                // only the args matter here, `crate = path` is applied to the whole generated file
+                if let Ok((tokens, _)) = crate_path::take_crate_override(tokens)
+                    && let Ok(preset) = syn::parse2::<AutoPresetArgs>(tokens)
+                {
+                    self.presets.insert(preset.name.to_string(), preset);
+                }
+                Ok(())
+            }
+            Item::Macro(item) if is_last_segment(&item.mac.path, "auto_generics_set") => {
+                let (tokens, _) = debug_expand::take_debug_expand_flag(item.mac.tokens);
+                if let Ok(set) = syn::parse2::<AutoGenericsSetArgs>(tokens) {
+                    self.generics_sets.insert(set.name.to_string(), set);
+                }
+                Ok(())
+            }
+            Item::Macro(item)
+                if applied_preset_args(&item).is_some() || is_applied_generics_set(&item) =>
+            {
+                // the macro may be defined in a later module and imported with `use`
+                if !self.visit_applied(item.clone(), scope)? {
+                    self.pending.push((item, scope.clone()));
+                }
+                Ok(())
+            }
+            Item::Mod(module) if !scope.in_fn => self.visit_module(module, scope),
+            Item::Fn(item) => {
+                let scope = Scope {
+                    in_fn: true,
+                    ..scope.clone()
+                };
+                item.block
+                    .stmts
+                    .into_iter()
+                    .try_for_each(|stmt| match stmt {
+                        Stmt::Item(item) => self.visit_item(item, &scope),
+                        _ => Ok(()),
+                    })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Expands uses of presets and generics sets that were only defined after them
+    pub fn visit_pending(&mut self) -> Result<(), Error> {
+        for (item, scope) in std::mem::take(&mut self.pending) {
+            self.visit_applied(item, &scope)?;
+        }
+        Ok(())
+    }
+
+    /// Expands `preset! { [args..] item }` and `set! { #[attribute(..)] item }` like the
+    /// `macro_rules!` `auto_preset!` and `auto_generics_set!` generate, `false` when the macro
+    /// wasn't seen yet
+    fn visit_applied(&mut self, item: ItemMacro, scope: &Scope) -> Result<bool, Error> {
+        let Some(segment) = item.mac.path.segments.last() else {
+            return Ok(false);
+        };
+        let name = segment.ident.to_string();
+        if let Some(set) = self.generics_sets.get(&name) {
+            let output = set
+                .apply(item.mac.tokens)
+                .map_err(|source| Error::Expansion {
+                    attribute: name.clone(),
+                    path: scope.file.clone(),
+                    source,
+                })?;
+            self.visit_expansion(&name, output, scope)?;
+            return Ok(true);
+        }
+        let Some(preset) = self.presets.get(&name) else {
+            return Ok(false);
+        };
+        let Some((args, rest)) = applied_preset_args(&item) else {
+            return Ok(false);
+        };
+        let args = NestedMeta::parse_meta_list(args).map_err(|source| Error::Expansion {
+            attribute: name.clone(),
+            path: scope.file.clone(),
+            source,
+        })?;
+        let attribute = AutoPresetExpandArgs {
+            attribute: preset.attribute.clone(),
+            preset: preset.args.clone(),
+            args,
+        }
+        .to_attribute();
+        self.visit_expansion(&name, quote! { #attribute #rest }, scope)?;
+        Ok(true)
+    }
+
+    fn visit_expansion(
+        &mut self,
+        attribute: &str,
+        output: MacroStream,
+        scope: &Scope,
+    ) -> Result<(), Error> {
+        let output = syn::parse2::<syn::File>(output).map_err(|source| Error::Expansion {
+            attribute: attribute.to_string(),
+            path: scope.file.clone(),
+            source,
+        })?;
+        output
+            .items
+            .into_iter()
+            .try_for_each(|item| self.visit_item(item, scope))
+    }
+
+    fn visit_module(&mut self, module: ItemMod, scope: &Scope) -> Result<(), Error> {
+        let name = module.ident.to_string();
+        let mut child = scope.clone();
+        child.module.push(name.clone());
+        match module.content {
+            Some((_, items)) => {
+                child.dir = scope.dir.join(&name);
+                child.inline = true;
+                items
+                    .into_iter()
+                    .try_for_each(|item| self.visit_item(item, &child))
+            }
+            None => {
+                let (file, is_mod_rs) = module_file(&module, scope)?;
+                child.dir = child_dir(&file, is_mod_rs);
+                child.file = file.clone();
+                child.inline = false;
+                self.visit_file(&file, &child)
+            }
+        }
+    }
+}
+
+type Expander = fn(MacroStream, MacroStream) -> MacroStream;
+
+/// The first attribute on `item` that's a global attribute macro
+fn find_global_attribute(item: &Item, attrs: &[Attribute]) -> Option<(usize, String, Expander)> {
+    attrs.iter().enumerate().find_map(|(ix, attr)| {
+        let name = attr.path().segments.last()?.ident.to_string();
+        // on anything but a fn `auto_plugin` is the derive's helper attribute
+        if name == "auto_plugin" && !matches!(item, Item::Fn(_)) {
+            return None;
+        }
+        let expand = global_attribute_expander(&name)?;
+        Some((ix, name, expand))
+    })
+}
+
+/// The args and item of `preset! { [args..] item }`, the invocation `auto_apply_preset` expands to
+fn applied_preset_args(item: &ItemMacro) -> Option<(MacroStream, MacroStream)> {
+    let mut tokens = item.mac.tokens.clone().into_iter();
+    match tokens.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+            Some((group.stream(), tokens.collect()))
+        }
+        _ => None,
+    }
+}
+
+/// Whether the macro is invoked with an attributed item, `set! { #[attribute(..)] item }`,
+/// the invocation `generics_set = set` expands to
+fn is_applied_generics_set(item: &ItemMacro) -> bool {
+    matches!(
+        item.mac.tokens.clone().into_iter().next(),
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '#'
+    )
+}
+
+fn derives_auto_plugin(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .any(|attr| {
+            attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            )
+            .is_ok_and(|paths| paths.iter().any(|path| is_last_segment(path, "AutoPlugin")))
+        })
+}
+
+fn is_last_segment(path: &syn::Path, name: &str) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
@@ -0,0 +1,382 @@
+//! Generates the global registry entries of a crate from `build.rs`, for targets where the
+//! `linkme` or `inventory` sections global mode relies on get stripped (LTO, dead-code stripping, some wasm setups).
+//!
+//! In `build.rs`:
+//! ```rust,ignore
+//! fn main() {
+//!     bevy_auto_plugin_build::generate().expect("failed to generate auto_plugins.rs");
+//! }
+//! ```
+//! and include the generated file at the crate root (`lib.rs` or `main.rs`):
+//! ```rust,ignore
+//! bevy_auto_plugin::modes::global::build_script::include_auto_plugins!();
+//! ```
+//! Only crates calling `generate` are affected, their entries are left out of the linker sections and registered
+//! from the generated list instead.
+//!
+//! The sources are parsed with `syn` and every `auto_*` attribute, `#[derive(AutoPlugin)]` and `auto_plugin_entry!`
+//! is expanded with the same code as the proc macros, so the listed entries build exactly like global mode.
+
+mod expand;
+mod modules;
+
+use bevy_auto_plugin_shared::__private::modes::global::build_script::ENTRIES_FILE_ENV;
+use bevy_auto_plugin_shared::__private::util::crate_path::CratePath;
+use proc_macro2::TokenStream as MacroStream;
+use quote::quote;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the generated file in `OUT_DIR`
+pub const OUT_FILE: &str = "auto_plugins.rs";
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read `{path}`: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to write `{path}`: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to parse `{path}`: {source}")]
+    Parse { path: PathBuf, source: syn::Error },
+    #[error("file for module `{module}` not found, looked for {candidates:?}")]
+    ModuleNotFound {
+        module: String,
+        candidates: Vec<PathBuf>,
+    },
+    #[error("failed to parse the expansion of `{attribute}` in `{path}`: {source}")]
+    Expansion {
+        attribute: String,
+        path: PathBuf,
+        source: syn::Error,
+    },
+    #[error("`{0}` is not set, `generate` is meant to run from a build script")]
+    MissingEnv(&'static str),
+    #[error("invalid crate path `{0}`")]
+    CratePath(String),
+}
+
+/// Generates `auto_plugins.rs` in `OUT_DIR` for the crate root `src/lib.rs` (or `src/main.rs`)
+pub fn generate() -> Result<PathBuf, Error> {
+    AutoPluginsBuilder::default().generate()
+}
+
+/// Options for generating `auto_plugins.rs`
+#[derive(Debug, Default, Clone)]
+pub struct AutoPluginsBuilder {
+    root: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+    crate_path: Option<String>,
+}
+
+impl AutoPluginsBuilder {
+    /// Crate root to start from, defaults to `src/lib.rs` or else `src/main.rs` in `CARGO_MANIFEST_DIR`
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+    /// Directory the file is written to, defaults to `OUT_DIR`
+    pub fn out_dir(mut self, out_dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = Some(out_dir.into());
+        self
+    }
+    /// Path to `bevy_auto_plugin` when it's only reachable through a re-export, like the attributes' `crate = path`
+    pub fn crate_path(mut self, crate_path: impl Into<String>) -> Self {
+        self.crate_path = Some(crate_path.into());
+        self
+    }
+
+    /// Writes the file (only when it changed), prints `cargo:rerun-if-changed` for every source read
+    /// and points `include_auto_plugins!` at the file
+    pub fn generate(self) -> Result<PathBuf, Error> {
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => out_dir.clone(),
+            None => std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or(Error::MissingEnv("OUT_DIR"))?,
+        };
+        let (contents, files) = self.generate_string()?;
+        for file in &files {
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+        let path = out_dir.join(OUT_FILE);
+        println!("cargo:rustc-env={ENTRIES_FILE_ENV}={}", path.display());
+        if std::fs::read_to_string(&path).is_ok_and(|existing| existing == contents) {
+            return Ok(path);
+        }
+        std::fs::write(&path, contents).map_err(|source| Error::Write {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(path)
+    }
+
+    /// The contents of `auto_plugins.rs` and every source file read to generate it
+    pub fn generate_string(&self) -> Result<(String, Vec<PathBuf>), Error> {
+        let root = match &self.root {
+            Some(root) => root.clone(),
+            None => default_root()?,
+        };
+        let crate_path = match &self.crate_path {
+            Some(path) => Some(
+                syn::parse_str::<syn::Path>(path).map_err(|_| Error::CratePath(path.clone()))?,
+            ),
+            None => None,
+        };
+        let mut collector = expand::EntryCollector::default();
+        let files = modules::walk_crate(&root, &mut collector)?;
+        let tokens = CratePath::resolve(crate_path).rewrite(render(&collector.entries));
+        let file = syn::parse2::<syn::File>(tokens).expect("generated file is valid");
+        let header = format!(
+            "// @generated by bevy_auto_plugin_build from `{}`, do not edit\n\n",
+            display_path(&root)
+        );
+        Ok((header + &prettyplease::unparse(&file), files))
+    }
+}
+
+fn default_root() -> Result<PathBuf, Error> {
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or(Error::MissingEnv("CARGO_MANIFEST_DIR"))?;
+    let lib = manifest_dir.join("src").join("lib.rs");
+    if lib.exists() {
+        return Ok(lib);
+    }
+    Ok(manifest_dir.join("src").join("main.rs"))
+}
+
+fn render(entries: &[expand::Entry]) -> MacroStream {
+    let listed = entries.iter().map(|entry| {
+        let expand::Entry { id_path, cfgs } = entry;
+        quote! {
+            #(#cfgs)*
+            impl ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::ListedBuildScriptEntry<
+                { ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::entry_id(#id_path) }
+            > for __BevyAutoPluginEntries {}
+        }
+    });
+    let adds = entries.iter().map(|entry| {
+        let expand::Entry { id_path, cfgs } = entry;
+        quote! {
+            #(#cfgs)*
+            add(<__BevyAutoPluginEntries as ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::BuildScriptEntry<
+                { ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::entry_id(#id_path) }
+            >>::ENTRY);
+        }
+    });
+    quote! {
+        /// Implements `BuildScriptEntry` for every registry entry of the crate
+        #[doc(hidden)]
+        #[allow(non_camel_case_types, dead_code)]
+        pub(crate) struct __BevyAutoPluginEntries;
+
+        #(#listed)*
+
+        #[doc(hidden)]
+        #[allow(dead_code, unused_variables)]
+        fn __bevy_auto_plugin_entries(
+            add: &mut dyn FnMut(&'static ::bevy_auto_plugin::__private::shared::__private::modes::global::GlobalAutoPluginRegistryEntryFactory),
+        ) {
+            #(#adds)*
+        }
+
+        /// Every registry entry of the crate, registered when one of its plugins is built.
+        /// Add it before other crates' plugins its entries are bound to
+        #[allow(dead_code)]
+        pub const AUTO_PLUGIN_ENTRIES: ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::BuildScriptEntries =
+            ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::BuildScriptEntries::new(
+                ::core::env!("CARGO_CRATE_NAME"),
+                __bevy_auto_plugin_entries,
+            );
+    }
+}
+
+/// `path` relative to `CARGO_MANIFEST_DIR` when it's inside it
+fn display_path(path: &Path) -> String {
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    manifest_dir
+        .and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_crate(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_auto_plugin_build_test_{name}_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        for (path, contents) in files {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        dir
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_generate_string() {
+        let dir = write_crate(
+            "modules",
+            &[
+                (
+                    "src/lib.rs",
+                    r#"
+                    mod systems;
+                    #[derive(AutoPlugin)]
+                    #[auto_plugin(impl_plugin_trait)]
+                    struct Test;
+                    #[cfg(feature = "foo")]
+                    #[auto_init_resource(plugin = Test)]
+                    #[derive(Resource, Default)]
+                    struct Foo;
+                    "#,
+                ),
+                (
+                    "src/systems/mod.rs",
+                    r#"
+                    #![cfg(test)]
+                    mod inner {
+                        #[auto_system(plugin = Test, schedule = Update)]
+                        fn count() {}
+                    }
+                    "#,
+                ),
+            ],
+        );
+        let (contents, files) = AutoPluginsBuilder::default()
+            .root(dir.join("src/lib.rs"))
+            .generate_string()
+            .unwrap();
+        assert_eq!(
+            files,
+            vec![dir.join("src/lib.rs"), dir.join("src/systems/mod.rs")]
+        );
+        assert!(contents.starts_with("// @generated"), "{contents}");
+        assert_eq!(contents.matches("add(").count(), 2, "{contents}");
+        assert_eq!(
+            contents.matches("ListedBuildScriptEntry<").count(),
+            2,
+            "{contents}"
+        );
+        assert!(
+            contents.contains("pub const AUTO_PLUGIN_ENTRIES"),
+            "{contents}"
+        );
+        assert!(
+            contents.contains("\"crate::systems::inner::_global_auto_plugin_add_systems__"),
+            "{contents}"
+        );
+        assert!(contents.contains("#[cfg(test)]"), "{contents}");
+        assert!(
+            contents.contains("\"crate::_global_auto_plugin_init_resource__"),
+            "{contents}"
+        );
+        assert!(contents.contains("#[cfg(feature = \"foo\")]"), "{contents}");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_generate_string_presets() {
+        let dir = write_crate(
+            "presets",
+            &[
+                (
+                    "src/lib.rs",
+                    r#"
+                    mod components;
+                    mod presets;
+                    #[derive(AutoPlugin)]
+                    #[auto_plugin(impl_plugin_trait)]
+                    struct Test;
+                    "#,
+                ),
+                (
+                    "src/components.rs",
+                    r#"
+                    #[auto_apply_preset(crate::presets::game_component, plugin = Test)]
+                    struct Foo;
+                    "#,
+                ),
+                (
+                    "src/presets.rs",
+                    r#"
+                    auto_preset!(game_component = auto_component(derive, reflect, register, auto_name));
+                    pub(crate) use game_component;
+                    "#,
+                ),
+            ],
+        );
+        let (contents, _) = AutoPluginsBuilder::default()
+            .root(dir.join("src/lib.rs"))
+            .generate_string()
+            .unwrap();
+        assert!(
+            contents.contains("\"crate::components::_global_auto_plugin_register_type__"),
+            "{contents}"
+        );
+        assert!(
+            contents.contains("\"crate::components::_global_auto_plugin_auto_name__"),
+            "{contents}"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_generate_string_generics_sets() {
+        let dir = write_crate(
+            "generics_sets",
+            &[(
+                "src/lib.rs",
+                r#"
+                #[derive(AutoPlugin)]
+                #[auto_plugin(impl_plugin_trait)]
+                struct Test;
+                auto_generics_set!(Numeric = [u8, u16, f32]);
+                #[auto_register_type(plugin = Test, generics_set = Numeric)]
+                struct Foo<T>(T);
+                #[auto_register_type(plugin = Test, generics_product(A = [u8, f32], B = [bool, ()]))]
+                struct Bar<A, B>(A, B);
+                "#,
+            )],
+        );
+        let (contents, _) = AutoPluginsBuilder::default()
+            .root(dir.join("src/lib.rs"))
+            .generate_string()
+            .unwrap();
+        // listed and added
+        assert_eq!(
+            contents
+                .matches("\"crate::_global_auto_plugin_register_type__")
+                .count(),
+            2 * 7,
+            "{contents}"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_generate_string_module_not_found() {
+        let dir = write_crate("missing", &[("src/lib.rs", "mod missing;")]);
+        let err = AutoPluginsBuilder::default()
+            .root(dir.join("src/lib.rs"))
+            .generate_string()
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::ModuleNotFound { module, .. } if module == "crate::missing"),
+            "{err}"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
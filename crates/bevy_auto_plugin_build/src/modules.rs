@@ -0,0 +1,77 @@
+//! Resolving `mod foo;` declarations to files the way rustc does
+
+use crate::Error;
+use crate::expand::{EntryCollector, Scope};
+use std::path::{Path, PathBuf};
+use syn::{Expr, ExprLit, ItemMod, Lit, Meta};
+
+/// Collects the entries of every module reachable from `root`, returning the files read
+pub(crate) fn walk_crate(
+    root: &Path,
+    collector: &mut EntryCollector,
+) -> Result<Vec<PathBuf>, Error> {
+    let scope = Scope::root(root.to_path_buf(), mod_rs_dir(root));
+    collector.visit_file(root, &scope)?;
+    collector.visit_pending()?;
+    Ok(std::mem::take(&mut collector.files))
+}
+
+/// Directory the children of a file module live in: next to `lib.rs`, `main.rs`, `mod.rs` or
+/// files loaded with `#[path]`, otherwise in a directory named after the file (`foo.rs` -> `foo/`)
+pub(crate) fn child_dir(file: &Path, is_mod_rs: bool) -> PathBuf {
+    let parent = file.parent().map(Path::to_path_buf).unwrap_or_default();
+    if is_mod_rs {
+        return parent;
+    }
+    match file.file_stem() {
+        Some(stem) => parent.join(stem),
+        None => parent,
+    }
+}
+
+fn mod_rs_dir(file: &Path) -> PathBuf {
+    child_dir(file, true)
+}
+
+/// File of the out-of-line `module` declared in `scope`, and whether it's a mod-rs file
+pub(crate) fn module_file(module: &ItemMod, scope: &Scope) -> Result<(PathBuf, bool), Error> {
+    let name = module.ident.to_string();
+    if let Some(path) = path_attr(module) {
+        let base = if scope.inline {
+            scope.dir.clone()
+        } else {
+            scope
+                .file
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        };
+        return Ok((base.join(path), true));
+    }
+    let candidates = vec![
+        scope.dir.join(format!("{name}.rs")),
+        scope.dir.join(&name).join("mod.rs"),
+    ];
+    match candidates.iter().position(|candidate| candidate.exists()) {
+        Some(0) => Ok((candidates[0].clone(), false)),
+        Some(_) => Ok((candidates[1].clone(), true)),
+        None => Err(Error::ModuleNotFound {
+            module: scope.child_module(&name),
+            candidates,
+        }),
+    }
+}
+
+fn path_attr(module: &ItemMod) -> Option<String> {
+    module.attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(name_value) if name_value.path.is_ident("path") => {
+            match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => Some(lit.value()),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
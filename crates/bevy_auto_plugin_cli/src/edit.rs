@@ -0,0 +1,125 @@
+//! Text edits at token spans, so rewritten sources keep their formatting and comments
+
+use quote::ToTokens;
+use std::ops::Range;
+
+#[derive(Debug)]
+struct Edit {
+    range: Range<usize>,
+    text: String,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Edits(Vec<Edit>);
+
+impl Edits {
+    pub fn insert(&mut self, at: usize, text: impl Into<String>) {
+        self.replace(at..at, text);
+    }
+
+    pub fn replace(&mut self, range: Range<usize>, text: impl Into<String>) {
+        self.0.push(Edit {
+            range,
+            text: text.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Applies the edits in source order, inserts at the same offset keep the order they were added in.
+    ///
+    /// Fails on an edit starting inside text another edit already replaced, rather than dropping either
+    pub fn apply(mut self, source: &str) -> Result<String, OverlappingEdits> {
+        self.0.sort_by_key(|edit| edit.range.start);
+        let mut output = String::with_capacity(source.len());
+        let mut replaced = 0..0;
+        for edit in self.0 {
+            if edit.range.start < replaced.end {
+                return Err(OverlappingEdits {
+                    first: replaced,
+                    second: edit.range,
+                });
+            }
+            output.push_str(&source[replaced.end..edit.range.start]);
+            output.push_str(&edit.text);
+            replaced = edit.range;
+        }
+        output.push_str(&source[replaced.end..]);
+        Ok(output)
+    }
+}
+
+/// Two edits touching the same text, which one to keep can't be decided here
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OverlappingEdits {
+    pub first: Range<usize>,
+    pub second: Range<usize>,
+}
+
+/// Byte range of `tokens` in the source they were parsed from
+pub(crate) fn range_of(tokens: &impl ToTokens) -> Range<usize> {
+    let mut trees = tokens.to_token_stream().into_iter();
+    let Some(first) = trees.next() else {
+        return 0..0;
+    };
+    let start = first.span().byte_range();
+    let end = trees
+        .last()
+        .map_or(start.end, |last| last.span().byte_range().end);
+    start.start..end
+}
+
+/// Leading whitespace of the line `offset` is on
+pub(crate) fn indent_at(source: &str, offset: usize) -> &str {
+    let line = &source[line_start(source, offset)..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+fn line_start(source: &str, offset: usize) -> usize {
+    source[..offset].rfind('\n').map_or(0, |ix| ix + 1)
+}
+
+/// `range` grown to whole lines (with the trailing newline) when nothing else is on them
+pub(crate) fn line_range(source: &str, range: Range<usize>) -> Range<usize> {
+    let start = line_start(source, range.start);
+    let end = source[range.end..]
+        .find('\n')
+        .map_or(source.len(), |ix| range.end + ix + 1);
+    let blank = |text: &str| text.trim().is_empty();
+    if blank(&source[start..range.start]) && blank(&source[range.end..end]) {
+        start..end
+    } else {
+        range
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[internal_test_proc_macro::xtest]
+    fn test_apply() {
+        let mut edits = Edits::default();
+        edits.replace(4..7, "bar");
+        edits.insert(0, "// a\n");
+        edits.insert(0, "// b\n");
+        edits.insert(7, "()");
+        assert_eq!(edits.apply("let foo;").unwrap(), "// a\n// b\nlet bar();");
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_apply_overlapping() {
+        let mut edits = Edits::default();
+        edits.replace(4..7, "bar");
+        edits.insert(5, "x");
+        assert_eq!(
+            edits.apply("let foo;"),
+            Err(OverlappingEdits {
+                first: 4..7,
+                second: 5..5,
+            })
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Library behind the `cargo auto-plugin` subcommand.
+//!
+//! - [`migrate`] rewrites sources using the deprecated `module` and `flat_file` modes to global mode
+//! - [`list`] reports what the global attributes in a crate register, and problems with them
+
+mod edit;
+pub mod list;
+pub mod migrate;
+
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read `{path}`: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to write `{path}`: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to parse `{path}`: {source}")]
+    Parse { path: PathBuf, source: syn::Error },
+    #[error(
+        "failed to migrate `{path}`: conflicting edits at lines {first_line} and {second_line}, nothing was written"
+    )]
+    OverlappingEdits {
+        path: PathBuf,
+        first_line: usize,
+        second_line: usize,
+    },
+}
+
+/// `path` itself or every `.rs` file under it, skipping `target` and hidden directories
+pub(crate) fn rust_files(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+    collect_rust_files(path, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_rust_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    let read_err = |source| Error::Read {
+        path: path.to_path_buf(),
+        source,
+    };
+    for entry in std::fs::read_dir(path).map_err(read_err)? {
+        let entry = entry.map_err(read_err)?;
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if path.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                collect_rust_files(&path, files)?;
+            }
+        } else if name.ends_with(".rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,933 @@
+//! Static report of what the global attributes in a crate's sources register, without building it.
+//!
+//! Each attribute has its global keys (`plugin`, `phase`, ..) taken off and shorthands are expanded into
+//! the attributes they stand for (`auto_component(register)` -> `auto_register_type`), then items are
+//! read with the same `ItemAttributeArgs::match_items` parsers the module and flat file modes use.
+
+use crate::Error;
+use bevy_auto_plugin_shared::__private::attribute::AutoPluginItemAttribute;
+use bevy_auto_plugin_shared::__private::attribute_args::ItemAttributeArgs;
+use bevy_auto_plugin_shared::__private::attribute_args::attributes::modes::global::auto_generics_set::{
+    AutoGenericsSetArgs, ExpandedGenericsArgs, GENERICS_SET_KEY, expand_generics_args,
+};
+use bevy_auto_plugin_shared::__private::attribute_args::attributes::modes::global::auto_preset::{
+    AutoApplyPresetArgs, AutoPresetArgs, AutoPresetExpandArgs,
+};
+use bevy_auto_plugin_shared::__private::attribute_args::attributes::prelude::*;
+use bevy_auto_plugin_shared::__private::attribute_args::attributes::shorthand::prelude::*;
+use bevy_auto_plugin_shared::__private::attribute_args::attributes::shorthand::{
+    ExpandAttrs, Mode, ShortHandAttribute,
+};
+use bevy_auto_plugin_shared::__private::util::concrete_path::ConcreteTargetPathWithGenericsCollection;
+use bevy_auto_plugin_shared::__private::util::crate_path;
+use bevy_auto_plugin_shared::__private::util::extensions::from_meta::FromMetaExt;
+use bevy_auto_plugin_shared::__private::util::extensions::item::ItemAttrsExt;
+use darling::FromMeta;
+use darling::ast::NestedMeta;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{ToTokens, quote};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, Item, Meta, PathArguments, Token};
+
+/// Keys every global attribute takes on top of its own
+const GLOBAL_KEYS: &[&str] = &[
+    "plugin",
+    "phase",
+    "sub_app",
+    "group",
+    "crate",
+    "debug_expand",
+];
+
+/// Order kinds are listed in
+const KINDS: &[AutoPluginItemAttribute] = &[
+    AutoPluginItemAttribute::RegisterType,
+    AutoPluginItemAttribute::RegisterStateType,
+    AutoPluginItemAttribute::AddEvent,
+    AutoPluginItemAttribute::InitResource,
+    AutoPluginItemAttribute::InsertResource,
+    AutoPluginItemAttribute::InitState,
+    AutoPluginItemAttribute::AutoName,
+    AutoPluginItemAttribute::AddSystem,
+    AutoPluginItemAttribute::AddObserver,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub path: PathBuf,
+    pub line: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.path.strip_prefix(".").unwrap_or(&self.path);
+        write!(f, "{}:{}", path.display(), self.line)
+    }
+}
+
+/// A registration made by an attribute
+#[derive(Debug, Clone)]
+pub struct Registration {
+    pub plugin: String,
+    pub kind: AutoPluginItemAttribute,
+    /// The registered type or fn, with its generics
+    pub target: String,
+    pub location: Location,
+    /// The shorthand attribute the registration came from
+    pub via: Option<&'static str>,
+    /// Item the attribute is on, attributes only ever register the item they're on
+    item: usize,
+    /// What else tells two registrations of the same target apart (the schedule of a system)
+    distinct: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Problem {
+    /// Bound to a plugin that isn't a `#[derive(AutoPlugin)]` type in the scanned sources
+    UnknownPlugin {
+        location: Location,
+        plugin: String,
+        kind: AutoPluginItemAttribute,
+        target: String,
+    },
+    /// An item registering the same target more than once with the same plugin
+    Duplicate {
+        plugin: String,
+        kind: AutoPluginItemAttribute,
+        target: String,
+        locations: Vec<(Location, Option<&'static str>)>,
+    },
+    /// A shorthand flag clashing with the item it's on
+    ShorthandConflict { location: Location, message: String },
+    /// Attribute args that don't parse
+    Invalid { location: Location, message: String },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPlugin {
+                location,
+                plugin,
+                kind,
+                target,
+            } => write!(
+                f,
+                "{location}: `{}` on `{target}` is bound to `{plugin}`, which isn't a `#[derive(AutoPlugin)]` type in the scanned sources",
+                kind.ident_str()
+            ),
+            Self::Duplicate {
+                plugin,
+                kind,
+                target,
+                locations,
+            } => {
+                let locations = locations
+                    .iter()
+                    .map(|(location, via)| match via {
+                        Some(via) => format!("{location} (via `{via}`)"),
+                        None => location.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "`{target}` is passed to `{}` of `{plugin}` more than once: {locations}",
+                    kind.ident_str()
+                )
+            }
+            Self::ShorthandConflict { location, message } | Self::Invalid { location, message } => {
+                write!(f, "{location}: {message}")
+            }
+        }
+    }
+}
+
+/// Every registration found and the problems with them
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub registrations: Vec<Registration>,
+    pub problems: Vec<Problem>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut by_plugin = BTreeMap::<&str, BTreeMap<usize, Vec<&Registration>>>::new();
+        for registration in &self.registrations {
+            by_plugin
+                .entry(&registration.plugin)
+                .or_default()
+                .entry(kind_order(registration.kind))
+                .or_default()
+                .push(registration);
+        }
+        for (plugin, kinds) in by_plugin {
+            writeln!(f, "{plugin}")?;
+            for (kind, registrations) in kinds {
+                let label = KINDS[kind].ident_str().trim_start_matches("auto_");
+                writeln!(f, "  {label}")?;
+                let width = registrations
+                    .iter()
+                    .map(|registration| registration.target.len())
+                    .max()
+                    .unwrap_or_default();
+                for registration in registrations {
+                    write!(
+                        f,
+                        "    {:width$}  {}",
+                        registration.target, registration.location
+                    )?;
+                    match registration.via {
+                        Some(via) => writeln!(f, " (via `{via}`)")?,
+                        None => writeln!(f)?,
+                    }
+                }
+            }
+        }
+        if self.registrations.is_empty() {
+            writeln!(f, "no registrations found")?;
+        }
+        if !self.problems.is_empty() {
+            let count = self.problems.len();
+            let s = if count == 1 { "" } else { "s" };
+            writeln!(f, "\n{count} problem{s}")?;
+            for problem in &self.problems {
+                writeln!(f, "  {problem}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn kind_order(kind: AutoPluginItemAttribute) -> usize {
+    KINDS
+        .iter()
+        .position(|listed| *listed == kind)
+        .expect("every kind is listed")
+}
+
+/// Reports on `path`, or every `.rs` file under it when it's a directory
+pub fn list_path(path: &Path) -> Result<Report, Error> {
+    let sources = crate::rust_files(path)?
+        .into_iter()
+        .map(|path| {
+            std::fs::read_to_string(&path)
+                .map(|source| (path.clone(), source))
+                .map_err(|source| Error::Read { path, source })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    list_sources(&sources)
+}
+
+/// Reports on `(path, source)` pairs, files still using the `module` or `flat_file` modes are skipped
+pub fn list_sources(sources: &[(PathBuf, String)]) -> Result<Report, Error> {
+    let mut files = Vec::new();
+    for (path, source) in sources {
+        if source.contains("modes::module::prelude") || source.contains("modes::flat_file::prelude")
+        {
+            continue;
+        }
+        let file = syn::parse_file(source).map_err(|err| Error::Parse {
+            path: path.clone(),
+            source: err,
+        })?;
+        files.push((path, file));
+    }
+    let mut scan = Scan::default();
+    // presets can be used in files before the one defining them
+    for (_, file) in &files {
+        scan.visit_presets(&file.items);
+    }
+    for (path, file) in &files {
+        scan.visit_items(path, &file.items);
+    }
+    Ok(scan.finish())
+}
+
+/// Plugin and location of an attribute left for `match_items` to find
+#[derive(Debug, Clone)]
+struct Origin {
+    plugin: syn::Path,
+    line: usize,
+    via: Option<&'static str>,
+}
+
+#[derive(Default)]
+struct Scan {
+    report: Report,
+    /// Idents of the `#[derive(AutoPlugin)]` types
+    plugins: BTreeSet<String>,
+    /// Items visited so far
+    items: usize,
+    /// `auto_preset!`s by name
+    presets: HashMap<String, AutoPresetArgs>,
+    /// `auto_generics_set!`s by name
+    generics_sets: HashMap<String, AutoGenericsSetArgs>,
+}
+
+impl Scan {
+    fn visit_presets(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Mod(module) => {
+                    if let Some((_, content)) = &module.content {
+                        self.visit_presets(content);
+                    }
+                }
+                Item::Macro(item)
+                    if item
+                        .mac
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "auto_preset") =>
+                {
+                    // `crate = path` is the only global key allowed at the top level of a preset
+                    let tokens = crate_path::take_crate_override(item.mac.tokens.clone())
+                        .map(|(tokens, _)| tokens);
+                    if let Ok(preset) = tokens.and_then(syn::parse2::<AutoPresetArgs>) {
+                        self.presets.insert(preset.name.to_string(), preset);
+                    }
+                }
+                Item::Macro(item)
+                    if item
+                        .mac
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "auto_generics_set") =>
+                {
+                    if let Ok(set) = syn::parse2::<AutoGenericsSetArgs>(item.mac.tokens.clone()) {
+                        self.generics_sets.insert(set.name.to_string(), set);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// The attribute `#[auto_apply_preset(..)]` stands for, if its preset is known
+    fn apply_preset(&self, attr: &Attribute) -> syn::Result<Option<Attribute>> {
+        let args = attr.parse_args::<AutoApplyPresetArgs>()?;
+        let Some(preset) = args
+            .preset
+            .segments
+            .last()
+            .and_then(|segment| self.presets.get(&segment.ident.to_string()))
+        else {
+            return Ok(None);
+        };
+        let attribute = AutoPresetExpandArgs {
+            attribute: preset.attribute.clone(),
+            preset: preset.args.clone(),
+            args: args.args,
+        }
+        .to_attribute();
+        let attrs = Attribute::parse_outer.parse2(attribute)?;
+        Ok(attrs.into_iter().next().map(last_segment_path))
+    }
+
+    /// `attr` with `generics_set = Set` and `generics_product(..)` expanded into `generics(..)` lists,
+    /// an unknown set is left out
+    fn expand_generics(
+        &self,
+        attr: Attribute,
+        name: &str,
+        item: &MacroStream,
+    ) -> syn::Result<Attribute> {
+        let Meta::List(list) = &attr.meta else {
+            return Ok(attr);
+        };
+        let mut args = Vec::new();
+        for arg in NestedMeta::parse_meta_list(list.tokens.clone())? {
+            match &arg {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident(GENERICS_SET_KEY) =>
+                {
+                    if let Expr::Path(path) = &name_value.value
+                        && let Some(set) =
+                            path.path.segments.last().and_then(|segment| {
+                                self.generics_sets.get(&segment.ident.to_string())
+                            })
+                    {
+                        args.extend(set.generics_args());
+                    }
+                }
+                arg => args.push(arg.to_token_stream()),
+            }
+        }
+        let args = match expand_generics_args(name, quote!(#(#args),*), item)? {
+            ExpandedGenericsArgs::Args(args) => args,
+            ExpandedGenericsArgs::Deferred(_) => unreachable!("sets are expanded above"),
+        };
+        let mut attr = attr.clone();
+        if let Meta::List(list) = &mut attr.meta {
+            list.tokens = args;
+        }
+        Ok(attr)
+    }
+
+    fn visit_items(&mut self, path: &Path, items: &[Item]) {
+        for item in items {
+            if let Item::Mod(module) = item
+                && let Some((_, content)) = &module.content
+            {
+                self.visit_items(path, content);
+                continue;
+            }
+            if let Some(ident) = derived_plugin(item) {
+                self.plugins.insert(ident);
+            }
+            self.items += 1;
+            let (item, origins) = self.rewrite_item(path, item);
+            self.collect::<RegisterTypeAttributeArgs>(path, &item, &origins);
+            self.collect::<RegisterStateTypeAttributeArgs>(path, &item, &origins);
+            self.collect::<AddEventAttributeArgs>(path, &item, &origins);
+            self.collect::<InitResourceAttributeArgs>(path, &item, &origins);
+            self.collect::<InsertResourceAttributeArgs>(path, &item, &origins);
+            self.collect::<InitStateAttributeArgs>(path, &item, &origins);
+            self.collect::<AutoNameAttributeArgs>(path, &item, &origins);
+            self.collect::<AddSystemAttributeArgs>(path, &item, &origins);
+            self.collect::<AddObserverAttributeArgs>(path, &item, &origins);
+        }
+    }
+
+    /// `item` with its attributes as `match_items` expects them, and where each came from
+    fn rewrite_item(&mut self, path: &Path, item: &Item) -> (Item, Vec<Option<Origin>>) {
+        let item_tokens = item.to_token_stream();
+        let mut item = item.clone();
+        let Ok(attrs) = item.attrs_mut() else {
+            return (item, Vec::new());
+        };
+        let original = std::mem::take(attrs);
+        let derives = derive_names(&original);
+        let mut origins = Vec::new();
+        let mut bound_plugin = None;
+        for attr in original {
+            let location = Location {
+                path: path.to_path_buf(),
+                line: attr.pound_token.span.start().line,
+            };
+            let attr = if attr
+                .path()
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "auto_apply_preset")
+            {
+                match self.apply_preset(&attr) {
+                    Ok(applied) => applied.unwrap_or(attr),
+                    Err(err) => {
+                        self.invalid(location, err);
+                        continue;
+                    }
+                }
+            } else {
+                attr
+            };
+            let Some(name) = attr
+                .path()
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+            else {
+                continue;
+            };
+            let kind = KINDS.iter().find(|kind| kind.ident_str() == name);
+            let shorthand = SHORTHANDS.iter().find(|(shorthand, _)| *shorthand == name);
+            if kind.is_none() && shorthand.is_none() && name != "auto_bind_plugin" {
+                attrs.push(attr);
+                origins.push(None);
+                continue;
+            }
+            let attr = match self.expand_generics(attr, &name, &item_tokens) {
+                Ok(attr) => attr,
+                Err(err) => {
+                    self.invalid(location, err);
+                    continue;
+                }
+            };
+            let (attr, plugin) = match split_global_args(&attr) {
+                Ok(split) => split,
+                Err(err) => {
+                    self.invalid(location, err);
+                    continue;
+                }
+            };
+            if name == "auto_bind_plugin" {
+                bound_plugin = plugin;
+                continue;
+            }
+            // without a plugin it doesn't compile, rustc reports that better
+            let Some(plugin) = plugin.or_else(|| bound_plugin.clone()) else {
+                continue;
+            };
+            match shorthand {
+                None => {
+                    attrs.push(attr);
+                    origins.push(Some(Origin {
+                        plugin,
+                        line: location.line,
+                        via: None,
+                    }));
+                }
+                Some((shorthand, expand)) => {
+                    let expanded = match expand(&attr.meta) {
+                        Ok(expanded) => expanded,
+                        Err(err) => {
+                            self.invalid(location, err.into());
+                            continue;
+                        }
+                    };
+                    self.check_shorthand(&location, shorthand, &expanded, &derives);
+                    for attr in expanded.attrs.into_iter().flat_map(|tokens| {
+                        Attribute::parse_outer.parse2(tokens).unwrap_or_default()
+                    }) {
+                        attrs.push(last_segment_path(attr));
+                        origins.push(Some(Origin {
+                            plugin: plugin.clone(),
+                            line: location.line,
+                            via: Some(shorthand),
+                        }));
+                    }
+                }
+            }
+        }
+        (item, origins)
+    }
+
+    fn collect<A: ItemAttributeArgs>(
+        &mut self,
+        path: &Path,
+        item: &Item,
+        origins: &[Option<Origin>],
+    ) {
+        let matches = match A::match_items(std::slice::from_ref(item)) {
+            Ok(matches) => matches,
+            Err(err) => {
+                let location = Location {
+                    path: path.to_path_buf(),
+                    line: err.span().start().line,
+                };
+                self.invalid(location, err);
+                return;
+            }
+        };
+        for matched in matches {
+            let Some(Some(origin)) = matched
+                .attributes
+                .iter()
+                .position(|attr| std::ptr::eq(attr, matched.matched_attribute))
+                .and_then(|ix| origins.get(ix))
+            else {
+                continue;
+            };
+            // a system can be added to several schedules, so its args tell registrations apart
+            let distinct = match A::attribute() {
+                AutoPluginItemAttribute::AddSystem => {
+                    matched.matched_attribute.meta.to_token_stream().to_string()
+                }
+                _ => String::new(),
+            };
+            for target in ConcreteTargetPathWithGenericsCollection::from(&matched) {
+                let target = target.to_token_stream().to_string().replace(' ', "");
+                self.report.registrations.push(Registration {
+                    plugin: plugin_name(&origin.plugin),
+                    kind: A::attribute(),
+                    target: target.replace(',', ", "),
+                    location: Location {
+                        path: path.to_path_buf(),
+                        line: origin.line,
+                    },
+                    via: origin.via,
+                    item: self.items,
+                    distinct: distinct.clone(),
+                });
+            }
+        }
+    }
+
+    /// Flags shorthand `derive`s of traits the item already derives, and `register` or `reflect` without `Reflect`
+    fn check_shorthand(
+        &mut self,
+        location: &Location,
+        shorthand: &str,
+        expanded: &ExpandAttrs,
+        derives: &[String],
+    ) {
+        let expanded_attrs = expanded
+            .attrs
+            .iter()
+            .flat_map(|tokens| {
+                Attribute::parse_outer
+                    .parse2(tokens.clone())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let expanded_derives = derive_names(&expanded_attrs);
+        for name in expanded_derives
+            .iter()
+            .filter(|name| derives.contains(name))
+        {
+            self.report.problems.push(Problem::ShorthandConflict {
+                location: location.clone(),
+                message: format!(
+                    "`derive` on `{shorthand}` derives `{name}`, which the item already derives"
+                ),
+            });
+        }
+        let reflects = expanded_attrs.iter().any(|attr| {
+            attr.path().is_ident("reflect")
+                || attr
+                    .path()
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "auto_register_type")
+        });
+        let has_reflect = derives
+            .iter()
+            .chain(&expanded_derives)
+            .any(|name| name == "Reflect");
+        if reflects && !has_reflect {
+            self.report.problems.push(Problem::ShorthandConflict {
+                location: location.clone(),
+                message: format!(
+                    "`register` and `reflect` on `{shorthand}` need `Reflect`, add `derive` or derive it on the item"
+                ),
+            });
+        }
+    }
+
+    fn invalid(&mut self, location: Location, err: syn::Error) {
+        self.report.problems.push(Problem::Invalid {
+            location,
+            message: err.to_string(),
+        });
+    }
+
+    fn finish(mut self) -> Report {
+        let mut unknown = BTreeSet::new();
+        let mut duplicates = BTreeMap::<_, Vec<&Registration>>::new();
+        for registration in &self.report.registrations {
+            let ident = registration.plugin.split('<').next().unwrap_or_default();
+            if !self.plugins.contains(ident) {
+                unknown.insert((
+                    registration.location.clone(),
+                    registration.plugin.clone(),
+                    kind_order(registration.kind),
+                    registration.target.clone(),
+                ));
+            }
+            duplicates
+                .entry((
+                    registration.item,
+                    &registration.plugin,
+                    kind_order(registration.kind),
+                    &registration.target,
+                    &registration.distinct,
+                ))
+                .or_default()
+                .push(registration);
+        }
+        let mut problems = unknown
+            .into_iter()
+            .map(|(location, plugin, kind, target)| Problem::UnknownPlugin {
+                location,
+                plugin,
+                kind: KINDS[kind],
+                target,
+            })
+            .collect::<Vec<_>>();
+        problems.extend(
+            duplicates
+                .into_iter()
+                .filter(|(_, registrations)| registrations.len() > 1)
+                .map(
+                    |((_, plugin, kind, target, _), registrations)| Problem::Duplicate {
+                        plugin: plugin.clone(),
+                        kind: KINDS[kind],
+                        target: target.clone(),
+                        locations: registrations
+                            .iter()
+                            .map(|registration| (registration.location.clone(), registration.via))
+                            .collect(),
+                    },
+                ),
+        );
+        problems.append(&mut self.report.problems);
+        self.report.problems = problems;
+        self.report
+            .registrations
+            .sort_by(|a, b| a.location.cmp(&b.location));
+        self.report
+    }
+}
+
+type Expand = fn(&Meta) -> darling::Result<ExpandAttrs>;
+
+const SHORTHANDS: &[(&str, Expand)] = &[
+    ("auto_component", expand::<ComponentAttributeArgs>),
+    ("auto_resource", expand::<ResourceAttributeArgs>),
+    ("auto_event", expand::<EventAttributeArgs>),
+    ("auto_states", expand::<StatesAttributeArgs>),
+    ("auto_system", expand::<SystemAttributeArgs>),
+    ("auto_observer", expand::<ObserverAttributeArgs>),
+];
+
+/// The attributes a shorthand stands for, without a plugin
+fn expand<T: FromMeta + ShortHandAttribute>(meta: &Meta) -> darling::Result<ExpandAttrs> {
+    T::from_meta_ext(meta).map(|args| args.expand_attrs(&Mode::Module))
+}
+
+/// `attr` without the global keys, and its `plugin`
+fn split_global_args(attr: &Attribute) -> syn::Result<(Attribute, Option<syn::Path>)> {
+    let mut attr = last_segment_path(attr.clone());
+    let Meta::List(list) = &mut attr.meta else {
+        return Ok((attr, None));
+    };
+    let mut plugin = None;
+    let mut rest = Vec::new();
+    for arg in NestedMeta::parse_meta_list(list.tokens.clone())? {
+        let key = match &arg {
+            NestedMeta::Meta(meta) => meta.path().get_ident().map(ToString::to_string),
+            NestedMeta::Lit(_) => None,
+        };
+        match key.as_deref() {
+            Some("plugin") => {
+                if let NestedMeta::Meta(Meta::NameValue(name_value)) = &arg
+                    && let Expr::Path(path) = &name_value.value
+                {
+                    plugin = Some(path.path.clone());
+                }
+            }
+            Some(key) if GLOBAL_KEYS.contains(&key) => {}
+            _ => rest.push(arg),
+        }
+    }
+    list.tokens = quote!(#(#rest),*);
+    Ok((attr, plugin))
+}
+
+/// `crate::plugins::MyPlugin::<u8>` -> `MyPlugin<u8>`, the same plugin however it's referred to
+fn plugin_name(path: &syn::Path) -> String {
+    let Some(segment) = path.segments.last() else {
+        return String::new();
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(generics) => {
+            let args = generics
+                .args
+                .iter()
+                .map(|arg| arg.to_token_stream().to_string().replace(' ', ""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{}<{args}>", segment.ident)
+        }
+        _ => segment.ident.to_string(),
+    }
+}
+
+/// `attr` with its path cut down to the attribute name, which `match_items` looks for
+fn last_segment_path(mut attr: Attribute) -> Attribute {
+    let path = attr.meta.path().clone();
+    if let Some(segment) = path.segments.last() {
+        let ident: syn::Path = segment.ident.clone().into();
+        match &mut attr.meta {
+            Meta::Path(path) => *path = ident,
+            Meta::List(list) => list.path = ident,
+            Meta::NameValue(name_value) => name_value.path = ident,
+        }
+    }
+    attr
+}
+
+/// Last segments of everything in the `#[derive(..)]`s
+fn derive_names(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .filter_map(|path| {
+            path.segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+        })
+        .collect()
+}
+
+fn derived_plugin(item: &Item) -> Option<String> {
+    let (ident, attrs) = match item {
+        Item::Struct(item) => (&item.ident, &item.attrs),
+        Item::Enum(item) => (&item.ident, &item.attrs),
+        _ => return None,
+    };
+    derive_names(attrs)
+        .iter()
+        .any(|name| name == "AutoPlugin")
+        .then(|| ident.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(source: &str) -> Report {
+        list_sources(&[(PathBuf::from("src/lib.rs"), source.to_string())]).unwrap()
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_list() {
+        let report = report(
+            r#"
+#[derive(AutoPlugin)]
+struct MyPlugin;
+
+#[auto_register_type(plugin = MyPlugin, generics(bool), generics(u32))]
+#[auto_init_resource(plugin = MyPlugin, generics(bool))]
+#[derive(Resource, Reflect)]
+struct Foo<T>(T);
+
+mod systems {
+    #[auto_bind_plugin(plugin = crate::MyPlugin)]
+    #[auto_component(derive, reflect, register)]
+    struct Bar;
+
+    #[auto_system(plugin = MyPlugin, schedule = Update)]
+    fn count() {}
+}
+"#,
+        );
+        assert!(report.problems.is_empty(), "{report}");
+        assert_eq!(
+            report.to_string(),
+            "\
+MyPlugin
+  register_type
+    Foo<bool>  src/lib.rs:5
+    Foo<u32>   src/lib.rs:5
+    Bar        src/lib.rs:12 (via `auto_component`)
+  init_resource
+    Foo<bool>  src/lib.rs:6
+  add_system
+    count  src/lib.rs:15 (via `auto_system`)
+"
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_list_presets() {
+        let report = report(
+            r#"
+#[derive(AutoPlugin)]
+struct MyPlugin;
+
+#[auto_apply_preset(crate::presets::game_component, plugin = MyPlugin, auto_name)]
+struct Foo;
+
+mod presets {
+    auto_preset!(game_component = auto_component(derive, reflect, register));
+    pub(crate) use game_component;
+}
+"#,
+        );
+        assert!(report.problems.is_empty(), "{report}");
+        assert_eq!(
+            report.to_string(),
+            "\
+MyPlugin
+  register_type
+    Foo  src/lib.rs:5 (via `auto_component`)
+  name
+    Foo  src/lib.rs:5 (via `auto_component`)
+"
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_list_generics_sets() {
+        let report = report(
+            r#"
+#[derive(AutoPlugin)]
+struct MyPlugin;
+
+auto_generics_set!(Flags = [bool, ()]);
+
+#[auto_register_type(plugin = MyPlugin, generics_set = Flags)]
+struct Foo<T>(T);
+
+#[auto_register_type(plugin = MyPlugin, generics_product(A = [u8, f32], B = [bool]))]
+struct Bar<A, B>(A, B);
+"#,
+        );
+        assert!(report.problems.is_empty(), "{report}");
+        assert_eq!(
+            report.to_string(),
+            "\
+MyPlugin
+  register_type
+    Foo<bool>       src/lib.rs:7
+    Foo<()>         src/lib.rs:7
+    Bar<u8, bool>   src/lib.rs:10
+    Bar<f32, bool>  src/lib.rs:10
+"
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_list_problems() {
+        let report = report(
+            r#"
+#[derive(AutoPlugin)]
+struct MyPlugin;
+
+#[auto_register_type(plugin = Missing)]
+#[derive(Reflect)]
+struct Foo;
+
+#[auto_register_type(plugin = MyPlugin)]
+#[auto_resource(plugin = MyPlugin, derive, register)]
+#[derive(Resource, Reflect)]
+struct Bar;
+
+#[auto_component(plugin = MyPlugin, register)]
+struct Baz;
+
+#[auto_init_resource(plugin = MyPlugin, bogus)]
+struct Qux;
+"#,
+        );
+        let problems = report
+            .problems
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(problems.len(), 5, "{problems:#?}");
+        assert!(
+            problems[0]
+                .starts_with("src/lib.rs:5: `auto_register_type` on `Foo` is bound to `Missing`")
+        );
+        assert_eq!(
+            problems[1],
+            "`Bar` is passed to `auto_register_type` of `MyPlugin` more than once: src/lib.rs:9, src/lib.rs:10 (via `auto_resource`)"
+        );
+        assert_eq!(
+            problems[2],
+            "src/lib.rs:10: `derive` on `auto_resource` derives `Resource`, which the item already derives"
+        );
+        assert!(problems[3].starts_with(
+            "src/lib.rs:14: `register` and `reflect` on `auto_component` need `Reflect`"
+        ));
+        assert!(
+            problems[4].starts_with("src/lib.rs:17: Unknown field: `bogus`"),
+            "{}",
+            problems[4]
+        );
+    }
+}
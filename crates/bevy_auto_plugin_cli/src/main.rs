@@ -0,0 +1,92 @@
+use bevy_auto_plugin_cli::{Error, list, migrate};
+use clap::{Args, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum Cargo {
+    AutoPlugin(AutoPluginArgs),
+}
+
+/// Tools for bevy_auto_plugin
+#[derive(Args)]
+#[command(version, about)]
+struct AutoPluginArgs {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Rewrite sources using the deprecated `module` and `flat_file` modes to global mode
+    Migrate {
+        /// File or directory to migrate
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Only report the files that would change
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// List what the global attributes register, grouped by plugin and kind, and flag problems
+    ///
+    /// Exits with an error when problems are found: bindings to unknown plugins, duplicate
+    /// registrations and shorthand flags clashing with their item
+    List {
+        /// File or directory to scan
+        #[arg(default_value = "src")]
+        path: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let Cargo::AutoPlugin(args) = Cargo::parse();
+    let result = match args.command {
+        Command::Migrate { path, dry_run } => run_migrate(&path, dry_run),
+        Command::List { path } => run_list(&path),
+    };
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_migrate(path: &Path, dry_run: bool) -> Result<ExitCode, Error> {
+    let mut changed = 0;
+    for migration in migrate::migrate_path(path)? {
+        for note in &migration.notes {
+            eprintln!("note: {}: {note}", migration.path.display());
+        }
+        if !migration.is_changed() {
+            continue;
+        }
+        changed += 1;
+        if dry_run {
+            println!("would migrate {}", migration.path.display());
+        } else {
+            migration.write()?;
+            println!("migrated {}", migration.path.display());
+        }
+    }
+    if changed == 0 {
+        println!("nothing to migrate");
+    } else {
+        println!(
+            "enable the `mode_global` feature of bevy_auto_plugin (on by default) in place of `mode_module`/`mode_flat_file`, then run `cargo fmt`"
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_list(path: &Path) -> Result<ExitCode, Error> {
+    let report = list::list_path(path)?;
+    print!("{report}");
+    Ok(if report.problems.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
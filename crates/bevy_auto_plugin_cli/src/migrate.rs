@@ -0,0 +1,611 @@
+//! Rewrites sources using the deprecated `module` and `flat_file` modes to global mode.
+//!
+//! - `modes::module::prelude` and `modes::flat_file::prelude` imports become `modes::global::prelude`
+//! - `#[auto_plugin(init_name = init)] mod foo { .. }` gets a `#[derive(AutoPlugin)] pub struct FooPlugin;`
+//!   and `init` becomes a `#[auto_plugin(plugin = FooPlugin)]` fn, so `foo::init(app)` keeps working
+//! - a flat file's `#[auto_plugin] fn` gets a plugin struct and `plugin = ..` the same way
+//! - items with a single `auto_*` attribute get `plugin = ..`, items with several get `#[auto_bind_plugin(..)]`
+//!
+//! Edits are made at token spans, so everything else in the file is left as it was.
+
+use crate::Error;
+use crate::edit::{Edits, indent_at, line_range, range_of};
+use std::path::{Path, PathBuf};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, Item, ItemFn, ItemMod, MacroDelimiter, Meta, Token, UseTree};
+
+/// Attributes that need to know their plugin in global mode
+const ITEM_ATTRIBUTES: &[&str] = &[
+    "auto_add_event",
+    "auto_add_observer",
+    "auto_add_system",
+    "auto_component",
+    "auto_event",
+    "auto_init_resource",
+    "auto_init_state",
+    "auto_insert_resource",
+    "auto_name",
+    "auto_observer",
+    "auto_register_state_type",
+    "auto_register_type",
+    "auto_resource",
+    "auto_states",
+    "auto_system",
+];
+
+/// A migrated source file
+#[derive(Debug, Clone)]
+pub struct FileMigration {
+    pub path: PathBuf,
+    pub source: String,
+    pub migrated: String,
+    /// Things the migration couldn't do and that need a look by hand
+    pub notes: Vec<String>,
+}
+
+impl FileMigration {
+    pub fn is_changed(&self) -> bool {
+        self.source != self.migrated
+    }
+
+    /// Overwrites the file with the migrated source
+    pub fn write(&self) -> Result<(), Error> {
+        std::fs::write(&self.path, &self.migrated).map_err(|source| Error::Write {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+/// Migrates `path`, or every `.rs` file under it when it's a directory
+pub fn migrate_path(path: &Path) -> Result<Vec<FileMigration>, Error> {
+    crate::rust_files(path)?
+        .into_iter()
+        .map(|path| {
+            let source = std::fs::read_to_string(&path).map_err(|source| Error::Read {
+                path: path.clone(),
+                source,
+            })?;
+            migrate_source(&path, &source)
+        })
+        .collect()
+}
+
+/// Migrates the `source` of the file at `path`, files not importing either prelude are left as they are
+pub fn migrate_source(path: &Path, source: &str) -> Result<FileMigration, Error> {
+    let file = syn::parse_file(source).map_err(|err| Error::Parse {
+        path: path.to_path_buf(),
+        source: err,
+    })?;
+    let mut migration = Migration {
+        path,
+        source,
+        edits: Edits::default(),
+        notes: Vec::new(),
+        module_mode: false,
+        flat_file_mode: false,
+        preludes: Vec::new(),
+        needs: Vec::new(),
+    };
+    migration.visit_uses(&file.items);
+    if migration.module_mode {
+        migration.visit_modules(&file.items);
+    }
+    if migration.flat_file_mode {
+        migration.visit_flat_file(&file.items);
+    }
+    migration.import_needed();
+    let Migration { edits, notes, .. } = migration;
+    let migrated = if edits.is_empty() {
+        source.to_string()
+    } else {
+        edits.apply(source).map_err(|err| {
+            let line = |offset: usize| source[..offset].matches('\n').count() + 1;
+            Error::OverlappingEdits {
+                path: path.to_path_buf(),
+                first_line: line(err.first.start),
+                second_line: line(err.second.start),
+            }
+        })?
+    };
+    Ok(FileMigration {
+        path: path.to_path_buf(),
+        source: source.to_string(),
+        migrated,
+        notes,
+    })
+}
+
+struct Migration<'a> {
+    path: &'a Path,
+    source: &'a str,
+    edits: Edits,
+    notes: Vec<String>,
+    module_mode: bool,
+    flat_file_mode: bool,
+    /// What follows `prelude::` in each migrated import
+    preludes: Vec<UseTree>,
+    /// Names the migrated code uses from the global prelude
+    needs: Vec<&'static str>,
+}
+
+impl Migration<'_> {
+    fn visit_uses(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Use(item) => self.visit_use_tree(&item.tree, None),
+                Item::Mod(ItemMod {
+                    content: Some((_, items)),
+                    ..
+                }) => self.visit_uses(items),
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_use_tree(&mut self, tree: &UseTree, parent: Option<&syn::Ident>) {
+        match tree {
+            UseTree::Path(path) => {
+                let is_mode = parent.is_some_and(|parent| parent == "modes");
+                if is_mode && (path.ident == "module" || path.ident == "flat_file") {
+                    if path.ident == "module" {
+                        self.module_mode = true;
+                    } else {
+                        self.flat_file_mode = true;
+                    }
+                    self.edits.replace(path.ident.span().byte_range(), "global");
+                    if let UseTree::Path(prelude) = &*path.tree
+                        && prelude.ident == "prelude"
+                    {
+                        self.preludes.push((*prelude.tree).clone());
+                    }
+                    return;
+                }
+                self.visit_use_tree(&path.tree, Some(&path.ident));
+            }
+            UseTree::Group(group) => group
+                .items
+                .iter()
+                .for_each(|tree| self.visit_use_tree(tree, parent)),
+            _ => {}
+        }
+    }
+
+    fn visit_modules(&mut self, items: &[Item]) {
+        for item in items {
+            let Item::Mod(module) = item else {
+                continue;
+            };
+            let Some((_, content)) = &module.content else {
+                continue;
+            };
+            match init_name_attr(&module.attrs) {
+                Some((attr, init_name)) => self.migrate_module(module, content, attr, &init_name),
+                None => self.visit_modules(content),
+            }
+        }
+    }
+
+    fn migrate_module(
+        &mut self,
+        module: &ItemMod,
+        content: &[Item],
+        attr: &Attribute,
+        init_name: &syn::Ident,
+    ) {
+        let plugin = plugin_name(&module.ident.to_string());
+        self.edits
+            .replace(line_range(self.source, range_of(attr)), "");
+        let leading_uses = content
+            .iter()
+            .take_while(|item| matches!(item, Item::Use(_)))
+            .count();
+        let module_indent = indent_at(self.source, range_of(module).start).to_string();
+        let indent = match content.first() {
+            Some(first) => indent_at(self.source, range_of(first).start).to_string(),
+            None => format!("{module_indent}    "),
+        };
+        let block = format!(
+            "{indent}#[derive(AutoPlugin)]\n\
+             {indent}pub struct {plugin};\n\
+             \n\
+             {indent}#[auto_plugin(plugin = {plugin})]\n\
+             {indent}pub(super) fn {init_name}(app: &mut App) {{}}"
+        );
+        // after the leading `use`s, or else first thing in the module
+        match leading_uses {
+            0 if content.is_empty() => self
+                .edits
+                .insert(module_open(module), format!("\n{block}\n{module_indent}")),
+            0 => self
+                .edits
+                .insert(module_open(module), format!("\n{block}\n")),
+            count => self
+                .edits
+                .insert(range_of(&content[count - 1]).end, format!("\n\n{block}")),
+        }
+        self.need("AutoPlugin");
+        for item in content {
+            self.bind_item(item, &plugin);
+        }
+    }
+
+    fn visit_flat_file(&mut self, items: &[Item]) {
+        let plugin_fns = items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Fn(item) => auto_plugin_attr(&item.attrs).map(|attr| (item, attr)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let bindable = items.iter().filter(
+            |item| !matches!(item, Item::Fn(item) if auto_plugin_attr(&item.attrs).is_some()),
+        );
+        let Some(&(plugin_fn, attr)) = plugin_fns.first() else {
+            if bindable
+                .clone()
+                .any(|item| !item_attributes(item).is_empty())
+            {
+                self.note("no `#[auto_plugin]` fn in this file, add `plugin = ..` to its attributes by hand");
+            }
+            return;
+        };
+        let plugin = flat_file_plugin_name(&plugin_fn.sig.ident.to_string(), self.path);
+        if plugin_fns.len() > 1 {
+            self.note(format!(
+                "several `#[auto_plugin]` fns, the items were bound to `{plugin}` of `{}`",
+                plugin_fn.sig.ident
+            ));
+        }
+        self.migrate_plugin_fn(plugin_fn, attr, &plugin);
+        for item in bindable {
+            self.bind_item(item, &plugin);
+        }
+    }
+
+    fn migrate_plugin_fn(&mut self, item: &ItemFn, attr: &Attribute, plugin: &str) {
+        let start = range_of(item).start;
+        let indent = indent_at(self.source, start).to_string();
+        let vis = match item.vis {
+            syn::Visibility::Inherited => String::new(),
+            ref vis => format!("{} ", &self.source[range_of(vis)]),
+        };
+        self.edits.insert(
+            start,
+            format!("#[derive(AutoPlugin)]\n{indent}{vis}struct {plugin};\n\n{indent}"),
+        );
+        self.add_plugin_arg(attr, plugin);
+        self.need("AutoPlugin");
+    }
+
+    /// Adds `plugin` to the single `auto_*` attribute of `item`, or `auto_bind_plugin` when there are several
+    fn bind_item(&mut self, item: &Item, plugin: &str) {
+        let attrs = item_attributes(item);
+        let bound = attrs
+            .iter()
+            .any(|attr| is_attr(attr, "auto_bind_plugin") || has_plugin_arg(attr));
+        match attrs.as_slice() {
+            _ if bound => {}
+            [] => {}
+            [attr] => self.add_plugin_arg(attr, plugin),
+            [first, ..] => {
+                let start = range_of(*first).start;
+                let indent = indent_at(self.source, start).to_string();
+                self.edits.insert(
+                    start,
+                    format!("#[auto_bind_plugin(plugin = {plugin})]\n{indent}"),
+                );
+                self.need("auto_bind_plugin");
+            }
+        }
+    }
+
+    fn add_plugin_arg(&mut self, attr: &Attribute, plugin: &str) {
+        match &attr.meta {
+            Meta::Path(path) => self
+                .edits
+                .insert(range_of(path).end, format!("(plugin = {plugin})")),
+            Meta::List(list) => {
+                let MacroDelimiter::Paren(paren) = &list.delimiter else {
+                    return;
+                };
+                let separator = if list.tokens.is_empty() { "" } else { ", " };
+                self.edits.insert(
+                    paren.span.open().byte_range().end,
+                    format!("plugin = {plugin}{separator}"),
+                );
+            }
+            Meta::NameValue(_) => {}
+        }
+    }
+
+    fn need(&mut self, name: &'static str) {
+        if !self.needs.contains(&name) {
+            self.needs.push(name);
+        }
+    }
+
+    /// Adds the names the migrated code uses to prelude imports that don't glob import them
+    fn import_needed(&mut self) {
+        let needs = std::mem::take(&mut self.needs);
+        for name in needs {
+            if self.preludes.iter().any(|tree| imports(tree, name)) {
+                continue;
+            }
+            match self.preludes.first() {
+                Some(UseTree::Group(group)) => {
+                    let at = group.brace_token.span.open().byte_range().end;
+                    self.edits.insert(at, format!("{name}, "));
+                }
+                Some(tree @ (UseTree::Name(_) | UseTree::Rename(_))) => {
+                    let range = range_of(tree);
+                    let existing = self.source[range.clone()].to_string();
+                    self.edits.replace(range, format!("{{{name}, {existing}}}"));
+                }
+                _ => self.note(format!(
+                    "import `{name}` from `bevy_auto_plugin::modes::global::prelude`"
+                )),
+            }
+        }
+    }
+
+    fn note(&mut self, note: impl Into<String>) {
+        self.notes.push(note.into());
+    }
+}
+
+/// `#[auto_plugin(init_name = ..)]` of a module mode module
+fn init_name_attr(attrs: &[Attribute]) -> Option<(&Attribute, syn::Ident)> {
+    let attr = auto_plugin_attr(attrs)?;
+    let args = attr
+        .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .ok()?;
+    args.into_iter().find_map(|arg| match arg {
+        Meta::NameValue(name_value) if name_value.path.is_ident("init_name") => {
+            match name_value.value {
+                Expr::Path(path) => path.path.get_ident().cloned().map(|ident| (attr, ident)),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+fn auto_plugin_attr(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|attr| is_attr(attr, "auto_plugin"))
+}
+
+fn is_attr(attr: &Attribute, name: &str) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
+
+/// The `auto_*` attributes of `item` that need a plugin
+fn item_attributes(item: &Item) -> Vec<&Attribute> {
+    let attrs = match item {
+        Item::Struct(item) => &item.attrs,
+        Item::Enum(item) => &item.attrs,
+        Item::Union(item) => &item.attrs,
+        Item::Fn(item) => &item.attrs,
+        Item::Type(item) => &item.attrs,
+        _ => return Vec::new(),
+    };
+    attrs
+        .iter()
+        .filter(|attr| ITEM_ATTRIBUTES.iter().any(|name| is_attr(attr, name)))
+        .collect()
+}
+
+fn has_plugin_arg(attr: &Attribute) -> bool {
+    attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .is_ok_and(|args| args.iter().any(|arg| arg.path().is_ident("plugin")))
+}
+
+/// Whether the import tree brings `name` into scope
+fn imports(tree: &UseTree, name: &str) -> bool {
+    match tree {
+        UseTree::Glob(_) => true,
+        UseTree::Name(use_name) => use_name.ident == name,
+        UseTree::Rename(rename) => rename.ident == name,
+        UseTree::Group(group) => group.items.iter().any(|tree| imports(tree, name)),
+        UseTree::Path(_) => false,
+    }
+}
+
+/// Offset right after the `{` of an inline module
+fn module_open(module: &ItemMod) -> usize {
+    module
+        .content
+        .as_ref()
+        .map_or(range_of(module).end, |(brace, _)| {
+            brace.span.open().byte_range().end
+        })
+}
+
+/// `foo_bar` -> `FooBarPlugin`, `foo_plugin` -> `FooPlugin`
+fn plugin_name(base: &str) -> String {
+    let name = base
+        .split(['_', '-'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+    if name.ends_with("Plugin") && name != "Plugin" {
+        name
+    } else {
+        format!("{name}Plugin")
+    }
+}
+
+/// Named after the fn, or after the file (or crate) when the fn is just `plugin` or `build`
+fn flat_file_plugin_name(fn_name: &str, path: &Path) -> String {
+    if !matches!(fn_name, "plugin" | "build") {
+        return plugin_name(fn_name);
+    }
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let name_of = |path: Option<&Path>| {
+        path.and_then(Path::file_name)
+            .map(|name| name.to_string_lossy().into_owned())
+    };
+    let base = match path.file_stem().map(|stem| stem.to_string_lossy()) {
+        Some(stem) if stem == "mod" => name_of(path.parent()),
+        Some(stem) if stem == "lib" || stem == "main" => {
+            name_of(path.parent().and_then(Path::parent))
+        }
+        Some(stem) => Some(stem.into_owned()),
+        None => None,
+    };
+    plugin_name(base.as_deref().unwrap_or("app"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate(path: &str, source: &str) -> FileMigration {
+        let migration = migrate_source(Path::new(path), source).unwrap();
+        syn::parse_file(&migration.migrated).expect("migrated source parses");
+        migration
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_migrate_module_mode() {
+        let migration = migrate(
+            "src/lib.rs",
+            r#"use bevy::prelude::*;
+use bevy_auto_plugin::modes::module::prelude::*;
+
+#[auto_plugin(init_name = init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_register_type]
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    #[auto_name]
+    pub struct FooComponent;
+
+    // keeps comments
+    #[auto_add_system(schedule = Update)]
+    fn foo_system() {}
+}
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+"#,
+        );
+        assert_eq!(
+            migration.migrated,
+            r#"use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+mod plugin_module {
+    use super::*;
+
+    #[derive(AutoPlugin)]
+    pub struct PluginModulePlugin;
+
+    #[auto_plugin(plugin = PluginModulePlugin)]
+    pub(super) fn init(app: &mut App) {}
+
+    #[auto_bind_plugin(plugin = PluginModulePlugin)]
+    #[auto_register_type]
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    #[auto_name]
+    pub struct FooComponent;
+
+    // keeps comments
+    #[auto_add_system(plugin = PluginModulePlugin, schedule = Update)]
+    fn foo_system() {}
+}
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+"#
+        );
+        assert!(migration.notes.is_empty(), "{:?}", migration.notes);
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_migrate_flat_file_mode() {
+        let migration = migrate(
+            "src/systems.rs",
+            r#"use bevy::prelude::*;
+use bevy_auto_plugin::modes::flat_file::prelude::{auto_init_resource, auto_plugin, auto_register_type};
+
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Default, Reflect)]
+struct FooResource;
+
+/// Docs stay on the fn
+#[auto_plugin(app_param = app)]
+pub fn plugin(app: &mut App) {}
+"#,
+        );
+        assert_eq!(
+            migration.migrated,
+            r#"use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::{AutoPlugin, auto_bind_plugin, auto_init_resource, auto_plugin, auto_register_type};
+
+#[auto_bind_plugin(plugin = SystemsPlugin)]
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Default, Reflect)]
+struct FooResource;
+
+#[derive(AutoPlugin)]
+pub struct SystemsPlugin;
+
+/// Docs stay on the fn
+#[auto_plugin(plugin = SystemsPlugin, app_param = app)]
+pub fn plugin(app: &mut App) {}
+"#
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_migrate_flat_file_mode_without_plugin_fn() {
+        let migration = migrate(
+            "src/foo.rs",
+            "use bevy_auto_plugin::modes::flat_file::prelude::*;\n\n#[auto_register_type]\nstruct Foo;\n",
+        );
+        assert_eq!(
+            migration.migrated,
+            "use bevy_auto_plugin::modes::global::prelude::*;\n\n#[auto_register_type]\nstruct Foo;\n"
+        );
+        assert_eq!(migration.notes.len(), 1);
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_global_mode_is_untouched() {
+        let source = "use bevy_auto_plugin::modes::global::prelude::*;\n\n#[auto_register_type(plugin = MyPlugin)]\nstruct Foo;\n";
+        let migration = migrate("src/lib.rs", source);
+        assert!(!migration.is_changed());
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_plugin_name() {
+        assert_eq!(plugin_name("plugin_module"), "PluginModulePlugin");
+        assert_eq!(plugin_name("audio_plugin"), "AudioPlugin");
+        assert_eq!(plugin_name("my-game"), "MyGamePlugin");
+        assert_eq!(
+            flat_file_plugin_name("plugin", Path::new("src/enemies/mod.rs")),
+            "EnemiesPlugin"
+        );
+        assert_eq!(
+            flat_file_plugin_name("build_ui", Path::new("src/lib.rs")),
+            "BuildUiPlugin"
+        );
+    }
+}
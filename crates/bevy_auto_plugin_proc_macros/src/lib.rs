@@ -1,14 +1,20 @@
+use bevy_auto_plugin_shared::__private::util::{crate_path, debug_expand};
 use proc_macro::TokenStream as CompilerStream;
 use proc_macro2::TokenStream as MacroStream;
 
 #[allow(dead_code)]
-/// thin adapter converting between the compiler-level and proc_macro2 streams
+/// thin adapter converting between the compiler-level and proc_macro2 streams,
+/// resolving the crate path (see [`crate_path`]) and dumping the expansion of `kind` when enabled (see [`debug_expand`])
 fn handle_attribute<F: Fn(MacroStream, MacroStream) -> MacroStream>(
+    kind: &str,
     handler: F,
     attr: CompilerStream,
     input: CompilerStream,
 ) -> CompilerStream {
-    handler(attr.into(), input.into()).into()
+    debug_expand::expand_attribute(kind, attr.into(), input.into(), |attr, input| {
+        crate_path::expand_attribute(attr, input, handler)
+    })
+    .into()
 }
 
 /* Module */
@@ -28,7 +34,7 @@ use bevy_auto_plugin_shared::__private::modes::module;
     )
 )]
 pub fn module_auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(module::inner::expand_module, attr, input)
+    handle_attribute("auto_plugin", module::inner::expand_module, attr, input)
 }
 
 /// Automatically registers a type with the Bevy `App`.
@@ -194,7 +200,12 @@ use bevy_auto_plugin_shared::__private::modes::flat_file;
     )
 )]
 pub fn flat_file_auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::expand_flat_file, attr, input)
+    handle_attribute(
+        "auto_plugin",
+        flat_file::inner::expand_flat_file,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers a type with the Bevy `App`.
@@ -210,6 +221,7 @@ pub fn flat_file_auto_plugin(attr: CompilerStream, input: CompilerStream) -> Com
 )]
 pub fn flat_file_auto_register_type(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     handle_attribute(
+        "auto_register_type",
         flat_file::inner::handle_register_type_attribute,
         attr,
         input,
@@ -228,7 +240,12 @@ pub fn flat_file_auto_register_type(attr: CompilerStream, input: CompilerStream)
     )
 )]
 pub fn flat_file_auto_add_event(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::handle_add_event_attribute, attr, input)
+    handle_attribute(
+        "auto_add_event",
+        flat_file::inner::handle_add_event_attribute,
+        attr,
+        input,
+    )
 }
 
 /// Automatically initializes a resource in the Bevy `App`.
@@ -244,6 +261,7 @@ pub fn flat_file_auto_add_event(attr: CompilerStream, input: CompilerStream) ->
 )]
 pub fn flat_file_auto_init_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     handle_attribute(
+        "auto_init_resource",
         flat_file::inner::handle_init_resource_attribute,
         attr,
         input,
@@ -262,7 +280,12 @@ pub fn flat_file_auto_init_resource(attr: CompilerStream, input: CompilerStream)
     )
 )]
 pub fn flat_file_auto_name(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::handle_auto_name_attribute, attr, input)
+    handle_attribute(
+        "auto_name",
+        flat_file::inner::handle_auto_name_attribute,
+        attr,
+        input,
+    )
 }
 
 /// Automatically initializes a State in the Bevy `App`.
@@ -277,7 +300,12 @@ pub fn flat_file_auto_name(attr: CompilerStream, input: CompilerStream) -> Compi
     )
 )]
 pub fn flat_file_auto_init_state(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::handle_init_state_attribute, attr, input)
+    handle_attribute(
+        "auto_init_state",
+        flat_file::inner::handle_init_state_attribute,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers a State type in the Bevy `App`.
@@ -296,6 +324,7 @@ pub fn flat_file_auto_register_state_type(
     input: CompilerStream,
 ) -> CompilerStream {
     handle_attribute(
+        "auto_register_state_type",
         flat_file::inner::handle_register_state_type_attribute,
         attr,
         input,
@@ -314,7 +343,12 @@ pub fn flat_file_auto_register_state_type(
     )
 )]
 pub fn flat_file_auto_add_system(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::handle_add_system_attribute, attr, input)
+    handle_attribute(
+        "auto_add_system",
+        flat_file::inner::handle_add_system_attribute,
+        attr,
+        input,
+    )
 }
 
 /// Automatically adds an observer to the Bevy `App`.
@@ -329,7 +363,12 @@ pub fn flat_file_auto_add_system(attr: CompilerStream, input: CompilerStream) ->
     )
 )]
 pub fn flat_file_auto_add_observer(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::handle_add_observer_attribute, attr, input)
+    handle_attribute(
+        "auto_add_observer",
+        flat_file::inner::handle_add_observer_attribute,
+        attr,
+        input,
+    )
 }
 
 /// Automatically inserts a resource in the Bevy `App`.
@@ -348,6 +387,7 @@ pub fn flat_file_auto_insert_resource(
     input: CompilerStream,
 ) -> CompilerStream {
     handle_attribute(
+        "auto_insert_resource",
         flat_file::inner::handle_insert_resource_attribute,
         attr,
         input,
@@ -366,7 +406,12 @@ pub fn flat_file_auto_insert_resource(
     )
 )]
 pub fn flat_file_auto_component(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::flat_file_auto_component, attr, input)
+    handle_attribute(
+        "auto_component",
+        flat_file::inner::flat_file_auto_component,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers item as Resource for bevy app. (See below for additional options)
@@ -381,7 +426,12 @@ pub fn flat_file_auto_component(attr: CompilerStream, input: CompilerStream) ->
     )
 )]
 pub fn flat_file_auto_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::flat_file_auto_resource, attr, input)
+    handle_attribute(
+        "auto_resource",
+        flat_file::inner::flat_file_auto_resource,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers item as Event for bevy app. (See below for additional options)
@@ -396,7 +446,12 @@ pub fn flat_file_auto_resource(attr: CompilerStream, input: CompilerStream) -> C
     )
 )]
 pub fn flat_file_auto_event(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::flat_file_auto_event, attr, input)
+    handle_attribute(
+        "auto_event",
+        flat_file::inner::flat_file_auto_event,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers item as States for bevy app. (See below for additional options)
@@ -411,7 +466,12 @@ pub fn flat_file_auto_event(attr: CompilerStream, input: CompilerStream) -> Comp
     )
 )]
 pub fn flat_file_auto_states(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::flat_file_auto_states, attr, input)
+    handle_attribute(
+        "auto_states",
+        flat_file::inner::flat_file_auto_states,
+        attr,
+        input,
+    )
 }
 
 /// Automatically adds the fn as a system for bevy app. (See below for additional options)
@@ -426,7 +486,12 @@ pub fn flat_file_auto_states(attr: CompilerStream, input: CompilerStream) -> Com
     )
 )]
 pub fn flat_file_auto_system(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::flat_file_auto_system, attr, input)
+    handle_attribute(
+        "auto_system",
+        flat_file::inner::flat_file_auto_system,
+        attr,
+        input,
+    )
 }
 
 /// Automatically adds flat_file observer to bevy app. (See below for additional options)
@@ -441,7 +506,12 @@ pub fn flat_file_auto_system(attr: CompilerStream, input: CompilerStream) -> Com
     )
 )]
 pub fn flat_file_auto_observer(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(flat_file::inner::flat_file_auto_observer, attr, input)
+    handle_attribute(
+        "auto_observer",
+        flat_file::inner::flat_file_auto_observer,
+        attr,
+        input,
+    )
 }
 
 /* global */
@@ -454,7 +524,14 @@ use bevy_auto_plugin_shared::__private::modes::global;
 #[proc_macro_derive(AutoPlugin, attributes(auto_plugin))]
 #[cfg(feature = "mode_global")]
 pub fn derive_global_auto_plugin(input: CompilerStream) -> CompilerStream {
-    global::inner::expand_global_derive_global_auto_plugin(input.into()).into()
+    debug_expand::expand_derive("derive_auto_plugin", "auto_plugin", input.into(), |input| {
+        crate_path::expand_derive(
+            "auto_plugin",
+            input,
+            global::inner::expand_global_derive_global_auto_plugin,
+        )
+    })
+    .into()
 }
 
 /// Attaches to a fn and injects a call to the initialization function that automatically registering types, events, and resources in the `App`.
@@ -463,7 +540,72 @@ pub fn derive_global_auto_plugin(input: CompilerStream) -> CompilerStream {
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::expand_global_auto_plugin, attr, input)
+    handle_attribute(
+        "auto_plugin",
+        global::inner::expand_global_auto_plugin,
+        attr,
+        input,
+    )
+}
+
+/// Registers an arbitrary `|app| { .. }` closure to run when the plugin is built.
+#[doc = include_str!("docs/global/auto_plugin_entry.md")]
+#[proc_macro]
+#[cfg(feature = "mode_global")]
+pub fn global_auto_plugin_entry(input: CompilerStream) -> CompilerStream {
+    debug_expand::expand_fn_like("auto_plugin_entry", input.into(), |input| {
+        crate_path::expand_fn_like(input, global::inner::expand_global_auto_plugin_entry)
+    })
+    .into()
+}
+
+/// Defines a named set of concrete types for `generics_set = Name`.
+#[doc = include_str!("docs/global/auto_generics_set.md")]
+#[proc_macro]
+#[cfg(feature = "mode_global")]
+pub fn global_auto_generics_set(input: CompilerStream) -> CompilerStream {
+    debug_expand::expand_fn_like(
+        "auto_generics_set",
+        input.into(),
+        global::inner::expand_global_auto_generics_set,
+    )
+    .into()
+}
+
+/// Defines a named preset of an `auto_*` attribute and its args.
+#[doc = include_str!("docs/global/auto_preset.md")]
+#[proc_macro]
+#[cfg(feature = "mode_global")]
+pub fn global_auto_preset(input: CompilerStream) -> CompilerStream {
+    debug_expand::expand_fn_like("auto_preset", input.into(), |input| {
+        crate_path::expand_fn_like(input, global::inner::expand_global_auto_preset)
+    })
+    .into()
+}
+
+/// Applies a preset defined with `auto_preset!`, merging in the args given.
+#[doc = include_str!("docs/global/auto_apply_preset.md")]
+#[proc_macro_attribute]
+#[cfg(feature = "mode_global")]
+pub fn global_auto_apply_preset(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(
+        "auto_apply_preset",
+        global::inner::global_auto_apply_preset_outer,
+        attr,
+        input,
+    )
+}
+
+#[doc(hidden)]
+#[proc_macro_attribute]
+#[cfg(feature = "mode_global")]
+pub fn global_auto_preset_expand(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(
+        "auto_preset_expand",
+        global::inner::global_auto_preset_expand_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers a type with the Bevy `App`.
@@ -471,7 +613,12 @@ pub fn global_auto_plugin(attr: CompilerStream, input: CompilerStream) -> Compil
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_register_type(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_register_type_outer, attr, input)
+    handle_attribute(
+        "auto_register_type",
+        global::inner::global_auto_register_type_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically adds an event type to the Bevy `App`.
@@ -479,7 +626,12 @@ pub fn global_auto_register_type(attr: CompilerStream, input: CompilerStream) ->
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_add_event(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_add_event_outer, attr, input)
+    handle_attribute(
+        "auto_add_event",
+        global::inner::global_auto_add_event_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically inserts a resource in the Bevy `App`.
@@ -487,7 +639,12 @@ pub fn global_auto_add_event(attr: CompilerStream, input: CompilerStream) -> Com
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_init_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_init_resource_outer, attr, input)
+    handle_attribute(
+        "auto_init_resource",
+        global::inner::global_auto_init_resource_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically inserts a resource in the Bevy `App`.
@@ -496,6 +653,7 @@ pub fn global_auto_init_resource(attr: CompilerStream, input: CompilerStream) ->
 #[cfg(feature = "mode_global")]
 pub fn global_auto_insert_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     handle_attribute(
+        "auto_insert_resource",
         global::inner::global_auto_insert_resource_outer,
         attr,
         input,
@@ -507,7 +665,12 @@ pub fn global_auto_insert_resource(attr: CompilerStream, input: CompilerStream)
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_init_state(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_init_state_outer, attr, input)
+    handle_attribute(
+        "auto_init_state",
+        global::inner::global_auto_init_state_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers a required component `Name` with a value using the concrete name of the item.
@@ -515,7 +678,12 @@ pub fn global_auto_init_state(attr: CompilerStream, input: CompilerStream) -> Co
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_name(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_name_outer, attr, input)
+    handle_attribute(
+        "auto_name",
+        global::inner::global_auto_name_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers item as States for bevy app. (See below for additional options)
@@ -527,6 +695,7 @@ pub fn global_auto_register_state_type(
     input: CompilerStream,
 ) -> CompilerStream {
     handle_attribute(
+        "auto_register_state_type",
         global::inner::global_auto_register_state_type_outer,
         attr,
         input,
@@ -538,7 +707,12 @@ pub fn global_auto_register_state_type(
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_add_system(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_add_system_outer, attr, input)
+    handle_attribute(
+        "auto_add_system",
+        global::inner::global_auto_add_system_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically adds the fn as a global observer to bevy app. (See below for additional options)
@@ -546,7 +720,12 @@ pub fn global_auto_add_system(attr: CompilerStream, input: CompilerStream) -> Co
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_add_observer(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_add_observer_outer, attr, input)
+    handle_attribute(
+        "auto_add_observer",
+        global::inner::global_auto_add_observer_outer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers item as Component for bevy app. (See below for additional options)
@@ -554,7 +733,12 @@ pub fn global_auto_add_observer(attr: CompilerStream, input: CompilerStream) ->
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_component(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_component, attr, input)
+    handle_attribute(
+        "auto_component",
+        global::inner::global_auto_component,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers item as Resource for bevy app. (See below for additional options)
@@ -562,7 +746,12 @@ pub fn global_auto_component(attr: CompilerStream, input: CompilerStream) -> Com
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_resource, attr, input)
+    handle_attribute(
+        "auto_resource",
+        global::inner::global_auto_resource,
+        attr,
+        input,
+    )
 }
 
 /// Automatically registers item as Event for bevy app. (See below for additional options)
@@ -570,7 +759,7 @@ pub fn global_auto_resource(attr: CompilerStream, input: CompilerStream) -> Comp
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_event(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_event, attr, input)
+    handle_attribute("auto_event", global::inner::global_auto_event, attr, input)
 }
 
 /// Automatically registers item as States for bevy app. (See below for additional options)
@@ -578,7 +767,12 @@ pub fn global_auto_event(attr: CompilerStream, input: CompilerStream) -> Compile
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_states(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_states, attr, input)
+    handle_attribute(
+        "auto_states",
+        global::inner::global_auto_states,
+        attr,
+        input,
+    )
 }
 
 /// Automatically adds the fn as a system for bevy app. (See below for additional options)
@@ -586,7 +780,12 @@ pub fn global_auto_states(attr: CompilerStream, input: CompilerStream) -> Compil
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_system(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_system, attr, input)
+    handle_attribute(
+        "auto_system",
+        global::inner::global_auto_system,
+        attr,
+        input,
+    )
 }
 
 /// Automatically adds global observer to bevy app. (See below for additional options)
@@ -594,7 +793,12 @@ pub fn global_auto_system(attr: CompilerStream, input: CompilerStream) -> Compil
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_observer(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_observer, attr, input)
+    handle_attribute(
+        "auto_observer",
+        global::inner::global_auto_observer,
+        attr,
+        input,
+    )
 }
 
 /// Automatically binds `plugin = _` to every auto_* attribute below it
@@ -602,5 +806,10 @@ pub fn global_auto_observer(attr: CompilerStream, input: CompilerStream) -> Comp
 #[proc_macro_attribute]
 #[cfg(feature = "mode_global")]
 pub fn global_auto_bind_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
-    handle_attribute(global::inner::global_auto_bind_plugin_outer, attr, input)
+    handle_attribute(
+        "auto_bind_plugin",
+        global::inner::global_auto_bind_plugin_outer,
+        attr,
+        input,
+    )
 }
@@ -1,6 +1,7 @@
 use crate::__private::attribute::AutoPluginItemAttribute;
 use crate::__private::attribute_args::{
-    AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs, ToTokensWithConcreteTargetPath,
+    AttributeKeys, AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs,
+    ToTokensWithConcreteTargetPath, checked_register_statement,
 };
 use crate::__private::item_with_attr_match::{ItemWithAttributeMatch, items_with_attribute_match};
 use crate::__private::type_list::TypeList;
@@ -21,6 +22,10 @@ pub struct AddEventAttributeArgs {
     pub generics: Vec<TypeList>,
 }
 
+impl AttributeKeys for AddEventAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics"];
+}
+
 impl AutoPluginAttributeKind for AddEventAttributeArgs {
     type Attribute = AutoPluginItemAttribute;
     fn attribute() -> AutoPluginItemAttribute {
@@ -57,6 +62,13 @@ impl ToTokensWithConcreteTargetPath for AddEventAttributeArgs {
             .add_message::< #target >()
         })
     }
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &TokenStream,
+        target: &ConcreteTargetPath,
+    ) -> TokenStream {
+        checked_register_statement("add_event", target, app, &[])
+    }
 }
 
 #[cfg(test)]
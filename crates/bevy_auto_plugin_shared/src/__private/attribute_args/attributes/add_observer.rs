@@ -1,7 +1,8 @@
 use crate::__private::attribute::AutoPluginItemAttribute;
 use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToTokens;
 use crate::__private::attribute_args::{
-    AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs, ToTokensWithConcreteTargetPath,
+    AttributeKeys, AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs,
+    ToTokensWithConcreteTargetPath, checked_target,
 };
 use crate::__private::item_with_attr_match::{ItemWithAttributeMatch, items_with_attribute_match};
 use crate::__private::type_list::TypeList;
@@ -20,6 +21,10 @@ pub struct AddObserverAttributeArgs {
     pub generics: Vec<TypeList>,
 }
 
+impl AttributeKeys for AddObserverAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics"];
+}
+
 impl AutoPluginAttributeKind for AddObserverAttributeArgs {
     type Attribute = AutoPluginItemAttribute;
     fn attribute() -> AutoPluginItemAttribute {
@@ -57,6 +62,14 @@ impl ToTokensWithConcreteTargetPath for AddObserverAttributeArgs {
             .add_observer(#target)
         })
     }
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &TokenStream,
+        target: &ConcreteTargetPath,
+    ) -> TokenStream {
+        let observer = checked_target("add_observer_target", target);
+        quote! { #app.add_observer(#observer); }
+    }
 }
 
 impl ArgsBackToTokens for AddObserverAttributeArgs {
@@ -2,7 +2,8 @@ use crate::__private::attribute::AutoPluginItemAttribute;
 use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToTokens;
 use crate::__private::attribute_args::schedule_config::ScheduleWithScheduleConfigArgs;
 use crate::__private::attribute_args::{
-    AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs, ToTokensWithConcreteTargetPath,
+    AttributeKeys, AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs,
+    ToTokensWithConcreteTargetPath, checked_target,
 };
 use crate::__private::item_with_attr_match::{ItemWithAttributeMatch, items_with_attribute_match};
 use crate::__private::type_list::TypeList;
@@ -23,6 +24,22 @@ pub struct AddSystemAttributeArgs {
     pub schedule_config: ScheduleWithScheduleConfigArgs,
 }
 
+impl AttributeKeys for AddSystemAttributeArgs {
+    const KEYS: &'static [&'static str] = &[
+        "generics",
+        "schedule",
+        "config",
+        "pipe",
+        "map",
+        "on_error",
+        "chain",
+        "chain_index",
+        "chain_config",
+    ];
+    const NESTED_KEYS: &'static [(&'static str, &'static [&'static str])] =
+        ScheduleWithScheduleConfigArgs::NESTED_KEYS;
+}
+
 impl AutoPluginAttributeKind for AddSystemAttributeArgs {
     type Attribute = AutoPluginItemAttribute;
     fn attribute() -> AutoPluginItemAttribute {
@@ -40,6 +57,9 @@ impl ItemAttributeArgs for AddSystemAttributeArgs {
     fn match_items(items: &[Item]) -> syn::Result<Vec<ItemWithAttributeMatch<'_, Self>>> {
         items_with_attribute_match::<FnMeta, AddSystemAttributeArgs>(items)
     }
+    fn ordering(&self) -> Vec<(&'static str, String)> {
+        self.schedule_config.ordering()
+    }
 }
 
 impl GenericsArgs for AddSystemAttributeArgs {
@@ -56,11 +76,44 @@ impl ToTokensWithConcreteTargetPath for AddSystemAttributeArgs {
         target: &ConcreteTargetPath,
     ) {
         let schedule = &self.schedule_config.schedule;
+        let system = self.schedule_config.adapters.wrap(target);
         let config_tokens = self.schedule_config.config.to_token_stream();
         tokens.extend(quote! {
-            .add_systems(#schedule, #target #config_tokens)
+            .add_systems(#schedule, #system #config_tokens)
         })
     }
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &TokenStream,
+        target: &ConcreteTargetPath,
+    ) -> TokenStream {
+        let schedule_config = &self.schedule_config;
+        let schedule = &schedule_config.schedule;
+        let system = schedule_config
+            .adapters
+            .wrap(checked_target("add_system_target", target));
+        let config_tokens = schedule_config.config.to_token_stream();
+        let (Some(chain), Some(chain_index)) =
+            (&schedule_config.chain, schedule_config.chain_index)
+        else {
+            return quote! { #app.add_systems(#schedule, #system #config_tokens); };
+        };
+        let global = quote! { ::bevy_auto_plugin::__private::shared::__private::modes::global };
+        let chain_config = schedule_config.chain_config.as_ref().map(|chain_config| {
+            quote! {
+                .configure_sets(#schedule, #global::SystemChain(#chain) #chain_config)
+            }
+        });
+        quote! {
+            #global::AutoPluginSystemChainExt::_add_chained_systems(
+                #app,
+                #schedule,
+                #chain,
+                #chain_index,
+                #system #config_tokens
+            ) #chain_config ;
+        }
+    }
 }
 
 impl ArgsBackToTokens for AddSystemAttributeArgs {
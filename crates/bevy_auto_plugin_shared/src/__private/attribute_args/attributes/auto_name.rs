@@ -1,7 +1,8 @@
 use crate::__private::attribute::AutoPluginItemAttribute;
 use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToTokens;
 use crate::__private::attribute_args::{
-    AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs, ToTokensWithConcreteTargetPath,
+    AttributeKeys, AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs,
+    ToTokensWithConcreteTargetPath, checked_register_statement,
 };
 use crate::__private::item_with_attr_match::{ItemWithAttributeMatch, items_with_attribute_match};
 use crate::__private::type_list::TypeList;
@@ -22,6 +23,10 @@ pub struct AutoNameAttributeArgs {
     pub generics: Vec<TypeList>,
 }
 
+impl AttributeKeys for AutoNameAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics"];
+}
+
 impl AutoPluginAttributeKind for AutoNameAttributeArgs {
     type Attribute = AutoPluginItemAttribute;
     fn attribute() -> AutoPluginItemAttribute {
@@ -47,24 +52,39 @@ impl GenericsArgs for AutoNameAttributeArgs {
     }
 }
 
+/// `|| Name::new("Target")`
+fn name_constructor(target: &ConcreteTargetPath) -> TokenStream {
+    // TODO: move to util fn
+    let name = quote!(#target)
+        .to_string()
+        .replace(" < ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",");
+    // TODO: offer option to only remove all spaces?
+    //  .replace(" ", "")
+    quote! {
+        || ::bevy_auto_plugin::__private::shared::__private::bevy_ecs::prelude::Name::new(#name)
+    }
+}
+
 impl ToTokensWithConcreteTargetPath for AutoNameAttributeArgs {
     fn to_tokens_with_concrete_target_path(
         &self,
         tokens: &mut TokenStream,
         target: &ConcreteTargetPath,
     ) {
-        // TODO: move to util fn
-        let name = quote!(#target)
-            .to_string()
-            .replace(" < ", "<")
-            .replace(" >", ">")
-            .replace(" ,", ",");
-        // TODO: offer option to only remove all spaces?
-        //  .replace(" ", "")
+        let name = name_constructor(target);
         tokens.extend(quote! {
-            .register_required_components_with::<#target, ::bevy_auto_plugin::__private::shared::__private::bevy_ecs::prelude::Name>(|| ::bevy_auto_plugin::__private::shared::__private::bevy_ecs::prelude::Name::new(#name))
+            .register_required_components_with::<#target, ::bevy_auto_plugin::__private::shared::__private::bevy_ecs::prelude::Name>(#name)
         })
     }
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &TokenStream,
+        target: &ConcreteTargetPath,
+    ) -> TokenStream {
+        checked_register_statement("auto_name", target, app, &[name_constructor(target)])
+    }
 }
 
 impl ArgsBackToTokens for AutoNameAttributeArgs {
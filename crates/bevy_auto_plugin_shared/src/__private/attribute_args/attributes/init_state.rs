@@ -1,7 +1,8 @@
 use crate::__private::attribute::AutoPluginItemAttribute;
 use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToTokens;
 use crate::__private::attribute_args::{
-    AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs, ToTokensWithConcreteTargetPath,
+    AttributeKeys, AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs,
+    ToTokensWithConcreteTargetPath, checked_register_statement,
 };
 use crate::__private::item_with_attr_match::{ItemWithAttributeMatch, items_with_attribute_match};
 use crate::__private::type_list::TypeList;
@@ -22,6 +23,10 @@ pub struct InitStateAttributeArgs {
     pub generics: Vec<TypeList>,
 }
 
+impl AttributeKeys for InitStateAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics"];
+}
+
 impl AutoPluginAttributeKind for InitStateAttributeArgs {
     type Attribute = AutoPluginItemAttribute;
     fn attribute() -> AutoPluginItemAttribute {
@@ -58,6 +63,13 @@ impl ToTokensWithConcreteTargetPath for InitStateAttributeArgs {
             .init_state::< #target >()
         })
     }
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &TokenStream,
+        target: &ConcreteTargetPath,
+    ) -> TokenStream {
+        checked_register_statement("init_state", target, app, &[])
+    }
 }
 
 impl ArgsBackToTokens for InitStateAttributeArgs {
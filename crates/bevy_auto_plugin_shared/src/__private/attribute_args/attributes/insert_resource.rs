@@ -1,6 +1,7 @@
 use crate::__private::attribute::AutoPluginItemAttribute;
 use crate::__private::attribute_args::{
-    AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs, ToTokensWithConcreteTargetPath,
+    AttributeKeys, AutoPluginAttributeKind, GenericsArgs, ItemAttributeArgs,
+    ToTokensWithConcreteTargetPath, checked_register_statement,
 };
 use crate::__private::expr_value::ExprValue;
 use crate::__private::item_with_attr_match::{ItemWithAttributeMatch, items_with_attribute_match};
@@ -12,28 +13,64 @@ use crate::__private::util::resolve_ident_from_item::{
 };
 use darling::FromMeta;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{Expr, Item};
+use syn::{Expr, Item, Path};
 
 #[derive(FromMeta, Debug, Clone, PartialEq, Hash)]
-#[darling(derive_syn_parse)]
+#[darling(derive_syn_parse, and_then = Self::validate_source)]
 pub struct InsertResourceAttributeArgs {
     #[darling(default)]
     pub generics: Option<TypeList>,
-    pub resource: ExprValue,
+    #[darling(default)]
+    pub resource: Option<ExprValue>,
+    /// `|plugin: &MyPlugin| Resource(..)`, global mode only
+    #[darling(default)]
+    pub from_plugin: Option<Expr>,
+}
+
+impl AttributeKeys for InsertResourceAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics", "resource", "from_plugin"];
 }
 
 impl InsertResourceAttributeArgs {
+    fn validate_source(self) -> darling::Result<Self> {
+        match (&self.resource, &self.from_plugin) {
+            (Some(_), None) | (None, Some(_)) => Ok(self),
+            (Some(_), Some(from_plugin)) => Err(darling::Error::custom(
+                "`resource` and `from_plugin` are mutually exclusive",
+            )
+            .with_span(from_plugin)),
+            (None, None) => Err(darling::Error::custom(
+                "expected either `resource(..)` or `from_plugin = ..`",
+            )),
+        }
+    }
+
+    /// Only global mode passes the plugin instance to its entries
+    pub fn validate_no_from_plugin(&self) -> syn::Result<()> {
+        match &self.from_plugin {
+            Some(from_plugin) => Err(syn::Error::new(
+                from_plugin.span(),
+                "`from_plugin` is only supported in global mode",
+            )),
+            None => Ok(()),
+        }
+    }
+
     pub fn validate_resource(&self) -> syn::Result<()> {
+        self.validate_no_from_plugin()?;
+        let Some(resource) = &self.resource else {
+            return Ok(());
+        };
         if !matches!(
-            self.resource.0,
+            resource.0,
             Expr::Call(_) // Foo(_)  or Foo::Bar(_)
             | Expr::Path(_) // Foo or Foo::Bar
             | Expr::Struct(_) // Foo { .. } or Foo::Bar { .. }
         ) {
             return Err(syn::Error::new(
-                self.resource.span(),
+                resource.span(),
                 "Expected a struct or enum value",
             ));
         }
@@ -66,23 +103,89 @@ impl GenericsArgs for InsertResourceAttributeArgs {
     }
 }
 
+impl InsertResourceAttributeArgs {
+    /// `plugin` is the plugin the entry is bound to, `from_plugin` fails to compile when it reads another one
+    fn resource_tokens(&self, plugin: Option<&Path>) -> TokenStream {
+        match (&self.resource, &self.from_plugin) {
+            (_, Some(from_plugin)) => {
+                let span = from_plugin.span();
+                // the bound plugin's instance when known, otherwise the one `from_plugin` reads
+                let (probe, from_plugin_fn) = match plugin {
+                    Some(plugin) => (
+                        quote_spanned! {span=> _PluginInstanceProbe::<#plugin>(::core::marker::PhantomData) },
+                        quote_spanned! {span=> _from_plugin::<#plugin, _> },
+                    ),
+                    None => (
+                        quote_spanned! {span=> _PluginInstanceProbe::of(&from_plugin) },
+                        quote_spanned! {span=> _from_plugin },
+                    ),
+                };
+                // fails to compile when the plugin has no instance to read, see `_PluginInstanceProbe`
+                let check = quote_spanned! {span=>
+                    // unused when the check fails
+                    #[allow(unused_imports)]
+                    use ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbeFallback as _;
+                    let probe = ::bevy_auto_plugin::__private::shared::__private::modes::global::#probe;
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::_require_plugin_instance(&probe, probe.probe());
+                };
+                // fails to compile when `from_plugin` reads another plugin than the bound one
+                let read = quote_spanned! {span=>
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::#from_plugin_fn(_plugin, from_plugin)
+                };
+                quote! {{
+                    let from_plugin = #from_plugin;
+                    #check
+                    #read
+                }}
+            }
+            (Some(resource), None) => quote! { #resource },
+            (None, None) => unreachable!("validated by darling"),
+        }
+    }
+}
+
 impl ToTokensWithConcreteTargetPath for InsertResourceAttributeArgs {
     fn to_tokens_with_concrete_target_path(
         &self,
         tokens: &mut TokenStream,
         target: &ConcreteTargetPath,
     ) {
-        let resource = &self.resource;
+        let resource = self.resource_tokens(None);
         tokens.extend(quote! {
             .insert_resource::< #target >(#resource)
         })
     }
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &TokenStream,
+        target: &ConcreteTargetPath,
+    ) -> TokenStream {
+        checked_register_statement(
+            "insert_resource",
+            target,
+            app,
+            &[self.resource_tokens(None)],
+        )
+    }
+    fn register_statement_for_plugin(
+        &self,
+        app: &TokenStream,
+        target: &ConcreteTargetPath,
+        plugin: &Path,
+    ) -> TokenStream {
+        checked_register_statement(
+            "insert_resource",
+            target,
+            app,
+            &[self.resource_tokens(Some(plugin))],
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::__private::attribute_args::WithTargetPath;
+    use crate::__private::attribute_args::{GlobalArgs, WithTargetPath};
     use syn::{Path, parse_quote, parse2};
 
     #[internal_test_proc_macro::xtest]
@@ -122,6 +225,76 @@ mod tests {
         Ok(())
     }
 
+    #[internal_test_proc_macro::xtest]
+    fn test_to_tokens_from_plugin() -> syn::Result<()> {
+        let args = parse2::<InsertResourceAttributeArgs>(quote!(
+            from_plugin = |p: &MyPlugin| FooTarget(p.0)
+        ))?;
+        let path: Path = parse_quote!(FooTarget);
+        let args_with_target = WithTargetPath::try_from((path, args))?;
+        let mut token_iter = args_with_target.to_tokens_iter();
+        assert_eq!(
+            token_iter.next().expect("token_iter").to_string(),
+            quote! {
+                .insert_resource :: < FooTarget > ({
+                    let from_plugin = |p: &MyPlugin| FooTarget(p.0);
+                    #[allow(unused_imports)]
+                    use ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbeFallback as _;
+                    let probe = ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbe::of(&from_plugin);
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::_require_plugin_instance(&probe, probe.probe());
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::_from_plugin(_plugin, from_plugin)
+                })
+            }
+            .to_string()
+        );
+        assert!(token_iter.next().is_none());
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_register_statement_for_plugin() -> syn::Result<()> {
+        let args = parse2::<GlobalArgs<InsertResourceAttributeArgs>>(quote!(
+            plugin = MyPlugin,
+            from_plugin = |p: &MyPlugin| FooTarget(p.0)
+        ))?;
+        let path: Path = parse_quote!(FooTarget);
+        let args_with_target = WithTargetPath::try_from((path, args))?;
+        let app = quote!(app);
+        let mut statement_iter = args_with_target.register_statements_iter(&app);
+        assert_eq!(
+            statement_iter.next().expect("statement_iter").to_string(),
+            quote! {
+                ::bevy_auto_plugin::__private::shared::__private::modes::global::checks::insert_resource::<FooTarget>(app, {
+                    let from_plugin = |p: &MyPlugin| FooTarget(p.0);
+                    #[allow(unused_imports)]
+                    use ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbeFallback as _;
+                    let probe = ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbe::<MyPlugin>(::core::marker::PhantomData);
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::_require_plugin_instance(&probe, probe.probe());
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::_from_plugin::<MyPlugin, _>(_plugin, from_plugin)
+                });
+            }
+            .to_string()
+        );
+        assert!(statement_iter.next().is_none());
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    #[should_panic(expected = "`resource` and `from_plugin` are mutually exclusive")]
+    fn test_resource_and_from_plugin() {
+        parse2::<InsertResourceAttributeArgs>(quote!(
+            resource(FooTarget(1)),
+            from_plugin = |p: &MyPlugin| FooTarget(p.0)
+        ))
+        .unwrap();
+    }
+
+    #[internal_test_proc_macro::xtest]
+    #[should_panic(expected = "expected either `resource(..)` or `from_plugin = ..`")]
+    fn test_missing_resource() {
+        parse2::<InsertResourceAttributeArgs>(quote!(generics(u8))).unwrap();
+    }
+
     #[internal_test_proc_macro::xtest]
     #[should_panic(expected = "Duplicate field `generics`")]
     fn test_to_tokens_multiple() {
@@ -0,0 +1,313 @@
+use crate::__private::generics::GenericsCollection;
+use crate::__private::type_list::TypeList;
+use crate::__private::util::extensions::item::ItemGenericsExt;
+use darling::ast::NestedMeta;
+use proc_macro2::{Span, TokenStream as MacroStream};
+use quote::{ToTokens, format_ident, quote};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, GenericArgument, Ident, Item, Meta, Token, bracketed};
+
+/// Key referencing a set defined with `auto_generics_set!`
+pub const GENERICS_SET_KEY: &str = "generics_set";
+/// Key expanding into the cartesian product of per-parameter type lists
+pub const GENERICS_PRODUCT_KEY: &str = "generics_product";
+
+/// `[u8, u16, u32]`, or const values (`[8, 16]`)
+fn parse_type_array(input: ParseStream) -> syn::Result<Vec<GenericArgument>> {
+    let content;
+    bracketed!(content in input);
+    Ok(content.parse::<TypeList>()?.0)
+}
+
+/// `auto_generics_set!(Numeric = [u8, u16, u32, f32])`,
+/// outer attributes (docs, `#[macro_export]`) are kept on the generated `macro_rules!`
+#[derive(Debug, Clone)]
+pub struct AutoGenericsSetArgs {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub types: Vec<GenericArgument>,
+}
+
+impl AutoGenericsSetArgs {
+    /// `generics(u8), generics(u16), ..`
+    pub fn generics_args(&self) -> Vec<MacroStream> {
+        self.types
+            .iter()
+            .map(|ty| quote! { generics(#ty) })
+            .collect()
+    }
+
+    /// `#[attr(args..)] item` with the set's generics added to the attribute, like the generated
+    /// `macro_rules!` does, for expanding sources outside of the compiler
+    pub fn apply(&self, tokens: MacroStream) -> syn::Result<MacroStream> {
+        let parser = |input: ParseStream| {
+            let attrs = input.call(Attribute::parse_outer)?;
+            let rest = input.parse::<MacroStream>()?;
+            Ok((attrs, rest))
+        };
+        let (mut attrs, rest) = parser.parse2(tokens)?;
+        let Some(first) = attrs.first_mut() else {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "expected the attribute using the generics set",
+            ));
+        };
+        let path = first.path().clone();
+        let args = match &first.meta {
+            Meta::List(list) => list.tokens.clone(),
+            _ => MacroStream::new(),
+        };
+        let generics = self.generics_args();
+        *first = syn::parse_quote!(#[#path(#(#generics,)* #args)]);
+        Ok(quote! { #(#attrs)* #rest })
+    }
+}
+
+impl Parse for AutoGenericsSetArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        let types = parse_type_array(input)?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after generics set"));
+        }
+        if types.is_empty() {
+            return Err(syn::Error::new(
+                name.span(),
+                "auto_generics_set expects at least one type",
+            ));
+        }
+        Ok(Self { attrs, name, types })
+    }
+}
+
+/// `generics_product(A = [u8, f32], B = [bool, ()])`
+#[derive(Debug, Clone)]
+pub struct GenericsProduct(pub Vec<(Ident, Vec<GenericArgument>)>);
+
+impl GenericsProduct {
+    /// Every combination, with the params put in the order the item declares them when it's known.
+    /// `arg` is the `generics_product(..)` argument, reported when one of the item's params is missing
+    pub fn generics(
+        &self,
+        item_params: Option<&[Ident]>,
+        arg: &impl ToTokens,
+    ) -> syn::Result<GenericsCollection> {
+        let params = match item_params.filter(|params| !params.is_empty()) {
+            None => self.0.iter().map(|(_, types)| types.clone()).collect(),
+            Some(item_params) => {
+                if let Some((ident, _)) = self
+                    .0
+                    .iter()
+                    .find(|(ident, _)| !item_params.contains(ident))
+                {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("`{ident}` is not a type or const parameter of the item"),
+                    ));
+                }
+                item_params
+                    .iter()
+                    .map(|param| {
+                        self.0
+                            .iter()
+                            .find(|(ident, _)| ident == param)
+                            .map(|(_, types)| types.clone())
+                            .ok_or_else(|| {
+                                syn::Error::new_spanned(
+                                    arg,
+                                    format!("generics_product is missing parameter `{param}`"),
+                                )
+                            })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?
+            }
+        };
+        Ok(GenericsCollection::product(&params))
+    }
+}
+
+impl Parse for GenericsProduct {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let params = Punctuated::<(Ident, Vec<GenericArgument>), Token![,]>::parse_terminated_with(
+            input,
+            |input| {
+                let ident = input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+                Ok((ident, parse_type_array(input)?))
+            },
+        )?;
+        Ok(Self(params.into_iter().collect()))
+    }
+}
+
+/// Attribute args after [`expand_generics_args`]
+#[derive(Debug)]
+pub enum ExpandedGenericsArgs {
+    /// The args with `generics_product(..)` expanded into `generics(..)` lists
+    Args(MacroStream),
+    /// The whole expansion: the set's `macro_rules!` invoked with the attribute and item,
+    /// adding its `generics(..)` lists and invoking the attribute again
+    Deferred(MacroStream),
+}
+
+/// Expands `generics_set = Set` and `generics_product(..)` in the args of the global `attribute` on `input`
+pub fn expand_generics_args(
+    attribute: &str,
+    attr: MacroStream,
+    input: &MacroStream,
+) -> syn::Result<ExpandedGenericsArgs> {
+    let is_key = |item: &NestedMeta, key: &str| matches!(item, NestedMeta::Meta(meta) if meta.path().is_ident(key));
+    let Ok(mut items) = NestedMeta::parse_meta_list(attr.clone()) else {
+        // leave reporting it to the attribute's own parsing
+        return Ok(ExpandedGenericsArgs::Args(attr));
+    };
+    if let Some(ix) = items.iter().position(|item| is_key(item, GENERICS_SET_KEY)) {
+        let set = match items.remove(ix) {
+            NestedMeta::Meta(Meta::NameValue(name_value)) => match name_value.value {
+                Expr::Path(path) if path.qself.is_none() => path.path,
+                value => {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "expected `generics_set = SetName`",
+                    ));
+                }
+            },
+            item => {
+                return Err(syn::Error::new_spanned(
+                    item,
+                    "expected `generics_set = SetName`",
+                ));
+            }
+        };
+        let attribute = format_ident!("{}", attribute);
+        return Ok(ExpandedGenericsArgs::Deferred(quote! {
+            #set! {
+                #[::bevy_auto_plugin::modes::global::prelude::#attribute(#(#items),*)]
+                #input
+            }
+        }));
+    }
+    if !items.iter().any(|item| is_key(item, GENERICS_PRODUCT_KEY)) {
+        return Ok(ExpandedGenericsArgs::Args(attr));
+    }
+    let item_params = syn::parse2::<Item>(input.clone()).ok().and_then(|item| {
+        item.generics().map(|generics| {
+            generics
+                .params
+                .iter()
+                .filter_map(|param| match param {
+                    syn::GenericParam::Type(param) => Some(param.ident.clone()),
+                    syn::GenericParam::Const(param) => Some(param.ident.clone()),
+                    syn::GenericParam::Lifetime(_) => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    let mut args = Vec::new();
+    for item in items {
+        match item {
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(GENERICS_PRODUCT_KEY) => {
+                let product = list.parse_args::<GenericsProduct>()?;
+                args.extend(
+                    product
+                        .generics(item_params.as_deref(), &list)?
+                        .to_attribute_arg_vec_tokens(),
+                );
+            }
+            item => args.push(item.to_token_stream()),
+        }
+    }
+    Ok(ExpandedGenericsArgs::Args(quote! { #(#args),* }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse2;
+
+    fn expand(attr: MacroStream, input: MacroStream) -> String {
+        match expand_generics_args("auto_register_type", attr, &input).unwrap() {
+            ExpandedGenericsArgs::Args(args) => args.to_string(),
+            ExpandedGenericsArgs::Deferred(output) => output.to_string(),
+        }
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_parse_set() -> syn::Result<()> {
+        let args = parse2::<AutoGenericsSetArgs>(quote! { Numeric = [u8, u16, Vec<u32>] })?;
+        assert_eq!(args.name, "Numeric");
+        let generics = args.generics_args();
+        assert_eq!(
+            quote!(#(#generics),*).to_string(),
+            quote!(generics(u8), generics(u16), generics(Vec<u32>)).to_string()
+        );
+        assert!(parse2::<AutoGenericsSetArgs>(quote! { Empty = [] }).is_err());
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_apply_set() -> syn::Result<()> {
+        let args = parse2::<AutoGenericsSetArgs>(quote! { Numeric = [u8, u16] })?;
+        assert_eq!(
+            args.apply(quote! { #[auto_register_type(plugin = Test)] struct Foo<T>(T); })?
+                .to_string(),
+            quote! { #[auto_register_type(generics(u8), generics(u16), plugin = Test)] struct Foo<T>(T); }
+                .to_string()
+        );
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_expand_generics_set() {
+        assert_eq!(
+            expand(
+                quote! { plugin = Test, generics_set = sets::Numeric },
+                quote! { struct Foo<T>(T); }
+            ),
+            quote! {
+                sets::Numeric! {
+                    #[::bevy_auto_plugin::modes::global::prelude::auto_register_type(plugin = Test)]
+                    struct Foo<T>(T);
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_expand_generics_product() {
+        assert_eq!(
+            expand(
+                quote! { plugin = Test, generics_product(B = [bool, ()], A = [u8, Vec<f32>]) },
+                quote! { struct Foo<A, B>(A, B); }
+            ),
+            quote! {
+                plugin = Test,
+                generics(u8, bool), generics(u8, ()), generics(Vec<f32>, bool), generics(Vec<f32>, ())
+            }
+            .to_string()
+        );
+        assert!(
+            expand_generics_args(
+                "auto_register_type",
+                quote! { plugin = Test, generics_product(C = [u8]) },
+                &quote! { struct Foo<A>(A); }
+            )
+            .is_err()
+        );
+        // nothing to expand leaves the args as they are
+        assert_eq!(
+            expand(
+                quote! { plugin = Test, generics(u8) },
+                quote! { struct Foo<A>(A); }
+            ),
+            quote! { plugin = Test, generics(u8) }.to_string()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::__private::attribute_args::AttributeKeys;
 use crate::__private::attribute_args::GenericsArgs;
 use crate::__private::type_list::TypeList;
 use darling::FromMeta;
@@ -12,6 +13,19 @@ pub struct AutoPluginStructOrEnumAttributeArgs {
     pub impl_plugin_trait: bool,
     pub impl_generic_auto_plugin_trait: bool,
     pub impl_generic_plugin_trait: bool,
+    pub insert_self_as_resource: bool,
+    pub is_unique: Option<bool>,
+}
+
+impl AttributeKeys for AutoPluginStructOrEnumAttributeArgs {
+    const KEYS: &'static [&'static str] = &[
+        "generics",
+        "impl_plugin_trait",
+        "impl_generic_auto_plugin_trait",
+        "impl_generic_plugin_trait",
+        "insert_self_as_resource",
+        "is_unique",
+    ];
 }
 
 impl GenericsArgs for AutoPluginStructOrEnumAttributeArgs {
@@ -27,6 +41,11 @@ pub struct AutoPluginFnAttributeArgs {
     pub generics: Vec<TypeList>,
     pub plugin: Option<Path>,
     pub app_param: Option<Ident>,
+    pub is_unique: Option<bool>,
+}
+
+impl AttributeKeys for AutoPluginFnAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics", "plugin", "app_param", "is_unique"];
 }
 
 impl GenericsArgs for AutoPluginFnAttributeArgs {
@@ -0,0 +1,242 @@
+use crate::__private::phase::PluginPhase;
+use darling::FromMeta;
+use proc_macro2::Ident;
+use quote::quote_spanned;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Expr, ExprClosure, LitStr, Path, Token, parse_quote};
+
+/// `auto_plugin_entry!(plugin = MyPlugin, phase = finish, |app| { .. })`
+/// or `auto_plugin_entry!(plugin = MyPlugin, group = "debug", |app, plugin| { .. })`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoPluginEntryArgs {
+    pub plugin: Path,
+    pub phase: PluginPhase,
+    pub group: Option<LitStr>,
+    pub build: ExprClosure,
+}
+
+impl AutoPluginEntryArgs {
+    pub fn global_build_prefix() -> &'static str {
+        "_global_auto_plugin_entry_"
+    }
+
+    /// Hashes the parsed args rather than their printed tokens, which differ between the compiler and
+    /// `bevy_auto_plugin_build` parsing the same source, along with where the invocation is so
+    /// identical invocations in one module don't collide
+    pub fn get_unique_ident(&self) -> Ident {
+        use std::hash::{Hash, Hasher};
+        use syn::spanned::Spanned;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        let start = self.plugin.span().start();
+        (start.line, start.column).hash(&mut hasher);
+        self.plugin.hash(&mut hasher);
+        self.phase.hash(&mut hasher);
+        self.group.as_ref().map(LitStr::value).hash(&mut hasher);
+        self.build.hash(&mut hasher);
+        Ident::new(
+            &format!("{}_{:x}", Self::global_build_prefix(), hasher.finish()),
+            proc_macro2::Span::call_site(),
+        )
+    }
+
+    /// Rewrites the user closure into the `|app, plugin: &dyn Any|` form the registry expects
+    pub fn entry_closure(&self) -> ExprClosure {
+        let mut inputs = self.build.inputs.iter();
+        let app = inputs.next().expect("validated while parsing");
+        let plugin_path = &self.plugin;
+        let body = &self.build.body;
+        let body: Expr = match inputs.next() {
+            Some(plugin) => {
+                let check = quote_spanned! {plugin.span()=> {
+                    // unused when the check fails
+                    #[allow(unused_imports)]
+                    use ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbeFallback as _;
+                    let probe = ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbe::<#plugin_path>(::core::marker::PhantomData);
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::_require_plugin_instance(&probe, probe.probe());
+                }};
+                parse_quote! {{
+                    #check
+                    let #plugin = ::bevy_auto_plugin::__private::shared::__private::modes::global::_downcast_plugin::<#plugin_path>(_plugin);
+                    #body
+                }}
+            }
+            None => parse_quote! { #body },
+        };
+        let mut closure = self.build.clone();
+        closure.inputs = [app.clone(), parse_quote!(_plugin)].into_iter().collect();
+        closure.body = Box::new(body);
+        closure
+    }
+}
+
+impl Parse for AutoPluginEntryArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_PLUGIN: &str =
+            "auto_plugin_entry expects `plugin = PluginType` as the first argument";
+        let key = input
+            .parse::<Ident>()
+            .map_err(|err| syn::Error::new(err.span(), EXPECTED_PLUGIN))?;
+        if key != "plugin" {
+            return Err(syn::Error::new(key.span(), EXPECTED_PLUGIN));
+        }
+        input.parse::<Token![=]>()?;
+        let plugin: Path = input.parse()?;
+        input.parse::<Token![,]>()?;
+
+        let mut phase = None;
+        let mut group = None;
+        while input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let value: Expr = input.parse()?;
+            input.parse::<Token![,]>()?;
+            match key.to_string().as_str() {
+                "phase" if phase.is_none() => {
+                    phase = Some(PluginPhase::from_expr(&value).map_err(syn::Error::from)?);
+                }
+                "phase" => return Err(syn::Error::new(key.span(), "duplicate `phase` argument")),
+                "group" if group.is_none() => {
+                    group = Some(LitStr::from_expr(&value).map_err(syn::Error::from)?);
+                }
+                "group" => return Err(syn::Error::new(key.span(), "duplicate `group` argument")),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown auto_plugin_entry argument `{key}`"),
+                    ));
+                }
+            }
+        }
+
+        let build: ExprClosure = input.parse()?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after closure"));
+        }
+        if !(1..=2).contains(&build.inputs.len()) {
+            return Err(syn::Error::new_spanned(
+                &build.inputs,
+                "auto_plugin_entry closure must accept `&mut App` and optionally `&PluginType` parameters",
+            ));
+        }
+        Ok(Self {
+            plugin,
+            phase: phase.unwrap_or_default(),
+            group,
+            build,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::{ToTokens, quote};
+    use syn::{parse_str, parse2};
+
+    #[internal_test_proc_macro::xtest]
+    fn test_parse() -> syn::Result<()> {
+        let args = parse2::<AutoPluginEntryArgs>(quote! {
+            plugin = MyPlugin, |app| { app.init_resource::<Foo>(); },
+        })?;
+        assert_eq!(args.plugin, syn::parse_quote!(MyPlugin));
+        assert_eq!(
+            args.build.to_token_stream().to_string(),
+            quote!(|app| {
+                app.init_resource::<Foo>();
+            })
+            .to_string()
+        );
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_parse_phase() -> syn::Result<()> {
+        let args = parse2::<AutoPluginEntryArgs>(quote! {
+            plugin = MyPlugin, phase = finish, |app| {}
+        })?;
+        assert_eq!(args.phase, PluginPhase::Finish);
+        let args = parse2::<AutoPluginEntryArgs>(quote! { plugin = MyPlugin, |app| {} })?;
+        assert_eq!(args.phase, PluginPhase::Build);
+        let args = parse2::<AutoPluginEntryArgs>(quote! {
+            plugin = MyPlugin, group = "debug", phase = cleanup, |app| {}
+        })?;
+        assert_eq!(args.phase, PluginPhase::Cleanup);
+        assert_eq!(
+            args.group.map(|group| group.value()).as_deref(),
+            Some("debug")
+        );
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    #[should_panic = "unknown auto_plugin_entry argument `foo`"]
+    fn test_parse_unknown_arg() {
+        parse2::<AutoPluginEntryArgs>(quote! { plugin = MyPlugin, foo = bar, |app| {} }).unwrap();
+    }
+
+    #[internal_test_proc_macro::xtest]
+    #[should_panic = "auto_plugin_entry expects `plugin = PluginType` as the first argument"]
+    fn test_parse_missing_plugin() {
+        parse2::<AutoPluginEntryArgs>(quote! { |app| {} }).unwrap();
+    }
+
+    #[internal_test_proc_macro::xtest]
+    #[should_panic = "auto_plugin_entry closure must accept `&mut App` and optionally `&PluginType` parameters"]
+    fn test_parse_wrong_param_count() {
+        parse2::<AutoPluginEntryArgs>(quote! { plugin = MyPlugin, |a, b, c| {} }).unwrap();
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_entry_closure() -> syn::Result<()> {
+        let args =
+            parse2::<AutoPluginEntryArgs>(quote! { plugin = MyPlugin, |app| { foo(app); } })?;
+        assert_eq!(
+            args.entry_closure().to_token_stream().to_string(),
+            quote!(|app, _plugin| {
+                foo(app);
+            })
+            .to_string()
+        );
+        let args = parse2::<AutoPluginEntryArgs>(quote! {
+            plugin = MyPlugin, |app, plugin| { foo(app, plugin); }
+        })?;
+        assert_eq!(
+            args.entry_closure().to_token_stream().to_string(),
+            quote!(|app, _plugin| {
+                {
+                    // unused when the check fails
+                    #[allow(unused_imports)]
+                    use ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbeFallback as _;
+                    let probe = ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginInstanceProbe::<MyPlugin>(::core::marker::PhantomData);
+                    ::bevy_auto_plugin::__private::shared::__private::modes::global::_require_plugin_instance(&probe, probe.probe());
+                }
+                let plugin = ::bevy_auto_plugin::__private::shared::__private::modes::global::_downcast_plugin::<MyPlugin>(_plugin);
+                { foo(app, plugin); }
+            })
+            .to_string()
+        );
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_unique_ident_differs() -> syn::Result<()> {
+        let a = parse2::<AutoPluginEntryArgs>(quote! { plugin = MyPlugin, |app| { foo(app); } })?;
+        let b = parse2::<AutoPluginEntryArgs>(quote! { plugin = MyPlugin, |app| { bar(app); } })?;
+        assert_ne!(a.get_unique_ident(), b.get_unique_ident());
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_unique_ident_differs_by_location() -> syn::Result<()> {
+        let source = "plugin = MyPlugin, |app| { foo(app); }";
+        let a = parse_str::<AutoPluginEntryArgs>(source)?;
+        let b = parse_str::<AutoPluginEntryArgs>(&format!("\n{source}"))?;
+        assert_eq!(a, b);
+        assert_ne!(a.get_unique_ident(), b.get_unique_ident());
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+use darling::ast::NestedMeta;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, Meta, Path, Token, bracketed};
+
+/// Attributes a preset can stand for
+pub const PRESET_ATTRIBUTES: &[&str] = &[
+    "auto_register_type",
+    "auto_add_event",
+    "auto_init_resource",
+    "auto_insert_resource",
+    "auto_init_state",
+    "auto_name",
+    "auto_register_state_type",
+    "auto_add_system",
+    "auto_add_observer",
+    "auto_component",
+    "auto_resource",
+    "auto_event",
+    "auto_states",
+    "auto_system",
+    "auto_observer",
+];
+
+/// Keys that may be given more than once, merged by keeping every occurrence
+const REPEATABLE_KEYS: &[&str] = &["generics"];
+
+/// `auto_preset!(game_component = auto_component(derive(Debug, Default), register))`,
+/// outer attributes (docs, `#[macro_export]`) are kept on the generated `macro_rules!`
+#[derive(Debug, Clone)]
+pub struct AutoPresetArgs {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub attribute: Ident,
+    pub args: Vec<NestedMeta>,
+}
+
+impl Parse for AutoPresetArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        let meta = input.parse::<Meta>()?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after preset"));
+        }
+        let attribute = match meta.path().get_ident() {
+            Some(ident) if PRESET_ATTRIBUTES.iter().any(|name| ident == name) => ident.clone(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    meta.path(),
+                    format!(
+                        "auto_preset expects one of: {}",
+                        PRESET_ATTRIBUTES
+                            .iter()
+                            .map(|name| format!("`{name}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ));
+            }
+        };
+        let args = match &meta {
+            Meta::Path(_) => Vec::new(),
+            Meta::List(list) => NestedMeta::parse_meta_list(list.tokens.clone())?,
+            Meta::NameValue(_) => {
+                return Err(syn::Error::new_spanned(
+                    &meta,
+                    "expected `attribute(args..)` or `attribute`",
+                ));
+            }
+        };
+        Ok(Self {
+            attrs,
+            name,
+            attribute,
+            args,
+        })
+    }
+}
+
+/// `#[auto_apply_preset(game_component, plugin = MyPlugin, ..)]`, the preset is the first bare path
+/// so args injected ahead of it (`auto_bind_plugin`) are still picked up
+#[derive(Debug, Clone)]
+pub struct AutoApplyPresetArgs {
+    pub preset: Path,
+    pub args: Vec<NestedMeta>,
+}
+
+impl Parse for AutoApplyPresetArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Punctuated::<NestedMeta, Token![,]>::parse_terminated(input)?
+            .into_iter()
+            .collect::<Vec<_>>();
+        let Some(ix) = args
+            .iter()
+            .position(|arg| matches!(arg, NestedMeta::Meta(Meta::Path(_))))
+        else {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "auto_apply_preset expects the preset as its first argument, e.g. `#[auto_apply_preset(my_preset, plugin = MyPlugin)]`",
+            ));
+        };
+        let NestedMeta::Meta(Meta::Path(preset)) = args.remove(ix) else {
+            unreachable!()
+        };
+        Ok(Self { preset, args })
+    }
+}
+
+/// What the `macro_rules!` generated for a preset passes on to the attribute expanding it:
+/// `auto_component(derive(Debug)), [plugin = MyPlugin]`
+#[derive(Debug, Clone)]
+pub struct AutoPresetExpandArgs {
+    pub attribute: Ident,
+    pub preset: Vec<NestedMeta>,
+    pub args: Vec<NestedMeta>,
+}
+
+impl AutoPresetExpandArgs {
+    /// The preset's attribute with the use site args merged into it
+    pub fn to_attribute(&self) -> MacroStream {
+        let attribute = &self.attribute;
+        let args = merge_args(self.preset.clone(), self.args.clone());
+        quote! {
+            #[::bevy_auto_plugin::modes::global::prelude::#attribute(#(#args),*)]
+        }
+    }
+}
+
+impl Parse for AutoPresetExpandArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attribute = input.parse::<Ident>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let preset = NestedMeta::parse_meta_list(content.parse()?)?;
+        input.parse::<Token![,]>()?;
+        let content;
+        bracketed!(content in input);
+        let args = NestedMeta::parse_meta_list(content.parse()?)?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self {
+            attribute,
+            preset,
+            args,
+        })
+    }
+}
+
+/// Merges the args given where a preset is used into the preset's own.
+///
+/// Lists given in both are combined (`derive(Debug)` + `derive(Clone)` -> `derive(Debug, Clone)`),
+/// a flag given alongside a list keeps the list, and anything else given at the use site replaces the preset's.
+pub fn merge_args(preset: Vec<NestedMeta>, args: Vec<NestedMeta>) -> Vec<NestedMeta> {
+    merge_nested(preset, args, true)
+}
+
+fn merge_nested(mut merged: Vec<NestedMeta>, args: Vec<NestedMeta>, top: bool) -> Vec<NestedMeta> {
+    for arg in args {
+        let key = match &arg {
+            NestedMeta::Meta(meta) => Some(meta_key(meta)),
+            NestedMeta::Lit(_) => None,
+        };
+        let existing = key.as_ref().and_then(|key| {
+            merged
+                .iter()
+                .position(|item| matches!(item, NestedMeta::Meta(meta) if meta_key(meta) == *key))
+        });
+        let Some(ix) = existing.filter(|_| {
+            !(top
+                && key
+                    .as_deref()
+                    .is_some_and(|key| REPEATABLE_KEYS.contains(&key)))
+        }) else {
+            merged.push(arg);
+            continue;
+        };
+        let (NestedMeta::Meta(current), NestedMeta::Meta(new)) = (&merged[ix], &arg) else {
+            unreachable!("only metas have keys")
+        };
+        merged[ix] = match (current, new) {
+            (Meta::List(current), Meta::List(new)) if top => {
+                let (Ok(current_items), Ok(new_items)) = (
+                    NestedMeta::parse_meta_list(current.tokens.clone()),
+                    NestedMeta::parse_meta_list(new.tokens.clone()),
+                ) else {
+                    merged[ix] = arg;
+                    continue;
+                };
+                let items = merge_nested(current_items, new_items, false);
+                let path = &current.path;
+                NestedMeta::Meta(syn::parse_quote!(#path(#(#items),*)))
+            }
+            // a flag alongside a list of the same key is already covered by the list
+            (Meta::List(_), Meta::Path(_)) => continue,
+            _ => arg,
+        };
+    }
+    merged
+}
+
+fn meta_key(meta: &Meta) -> String {
+    meta.path().to_token_stream().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse2;
+
+    fn merged(preset: MacroStream, args: MacroStream) -> String {
+        let preset = NestedMeta::parse_meta_list(preset).unwrap();
+        let args = NestedMeta::parse_meta_list(args).unwrap();
+        let merged = merge_args(preset, args);
+        quote!(#(#merged),*).to_string()
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_parse() -> syn::Result<()> {
+        let args = parse2::<AutoPresetArgs>(quote! {
+            /// docs
+            game_component = auto_component(derive(Debug, Default), register)
+        })?;
+        assert_eq!(args.attrs.len(), 1);
+        assert_eq!(args.name, "game_component");
+        assert_eq!(args.attribute, "auto_component");
+        assert_eq!(args.args.len(), 2);
+        let args = parse2::<AutoPresetArgs>(quote! { named = auto_name })?;
+        assert!(args.args.is_empty());
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    #[should_panic = "auto_preset expects one of: `auto_register_type`"]
+    fn test_parse_unknown_attribute() {
+        parse2::<AutoPresetArgs>(quote! { game_component = auto_plugin(impl_plugin_trait) })
+            .unwrap();
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_parse_apply() -> syn::Result<()> {
+        let args =
+            parse2::<AutoApplyPresetArgs>(quote! { game_component, plugin = Test, register })?;
+        assert_eq!(args.preset, syn::parse_quote!(game_component));
+        assert_eq!(args.args.len(), 2);
+        let args =
+            parse2::<AutoApplyPresetArgs>(quote! { plugin = Test, presets::game_component })?;
+        assert_eq!(args.preset, syn::parse_quote!(presets::game_component));
+        assert!(parse2::<AutoApplyPresetArgs>(quote! { plugin = Test }).is_err());
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_merge_args() {
+        assert_eq!(
+            merged(
+                quote! { derive(Debug, Default), reflect(Debug), register },
+                quote! { plugin = Test, derive(Clone, Debug), auto_name },
+            ),
+            quote! { derive(Debug, Default, Clone), reflect(Debug), register, plugin = Test, auto_name }
+                .to_string()
+        );
+        assert_eq!(
+            merged(quote! { derive(Debug) }, quote! { derive }),
+            quote! { derive(Debug) }.to_string()
+        );
+        assert_eq!(
+            merged(quote! { derive }, quote! { derive(Debug) }),
+            quote! { derive(Debug) }.to_string()
+        );
+        assert_eq!(
+            merged(quote! { register }, quote! { register = false }),
+            quote! { register = false }.to_string()
+        );
+        assert_eq!(
+            merged(quote! { generics(u8) }, quote! { generics(bool) }),
+            quote! { generics(u8), generics(bool) }.to_string()
+        );
+        assert_eq!(
+            merged(
+                quote! { schedule = Update, config(run_if = a, after = b) },
+                quote! { config(run_if = c) },
+            ),
+            quote! { schedule = Update, config(run_if = c, after = b) }.to_string()
+        );
+    }
+}
@@ -1 +1,4 @@
+pub mod auto_generics_set;
 pub mod auto_plugin;
+pub mod auto_plugin_entry;
+pub mod auto_preset;
@@ -5,7 +5,7 @@ use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToT
 use crate::__private::attribute_args::attributes::shorthand::{
     AutoPluginShortHandAttribute, ExpandAttrs, Mode, ShortHandAttribute, tokens,
 };
-use crate::__private::attribute_args::{AutoPluginAttributeKind, GenericsArgs};
+use crate::__private::attribute_args::{AttributeKeys, AutoPluginAttributeKind, GenericsArgs};
 use crate::__private::flag_or_list::FlagOrList;
 use crate::__private::non_empty_path::NonEmptyPath;
 use crate::__private::type_list::TypeList;
@@ -25,6 +25,11 @@ pub struct ComponentAttributeArgs {
     pub auto_name: bool,
 }
 
+impl AttributeKeys for ComponentAttributeArgs {
+    const KEYS: &'static [&'static str] =
+        &["generics", "derive", "reflect", "register", "auto_name"];
+}
+
 impl GenericsArgs for ComponentAttributeArgs {
     fn type_lists(&self) -> &[TypeList] {
         &self.generics
@@ -47,8 +52,10 @@ impl<'a> From<&'a ComponentAttributeArgs> for RegisterTypeAttributeArgs {
 }
 
 impl<'a> From<&'a ComponentAttributeArgs> for AutoNameAttributeArgs {
-    fn from(_: &'a ComponentAttributeArgs) -> Self {
-        Self::default()
+    fn from(value: &'a ComponentAttributeArgs) -> Self {
+        Self {
+            generics: value.generics.clone(),
+        }
     }
 }
 
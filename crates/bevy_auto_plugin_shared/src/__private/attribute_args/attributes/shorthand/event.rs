@@ -3,7 +3,7 @@ use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToT
 use crate::__private::attribute_args::attributes::shorthand::{
     AutoPluginShortHandAttribute, ExpandAttrs, Mode, ShortHandAttribute, tokens,
 };
-use crate::__private::attribute_args::{AutoPluginAttributeKind, GenericsArgs};
+use crate::__private::attribute_args::{AttributeKeys, AutoPluginAttributeKind, GenericsArgs};
 use crate::__private::flag_or_list::FlagOrList;
 use crate::__private::non_empty_path::NonEmptyPath;
 use crate::__private::type_list::TypeList;
@@ -21,6 +21,10 @@ pub struct EventAttributeArgs {
     pub register: bool,
 }
 
+impl AttributeKeys for EventAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics", "derive", "reflect", "register"];
+}
+
 impl GenericsArgs for EventAttributeArgs {
     fn type_lists(&self) -> &[TypeList] {
         &self.generics
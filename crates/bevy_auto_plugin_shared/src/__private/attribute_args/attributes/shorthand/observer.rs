@@ -5,7 +5,7 @@ use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToT
 use crate::__private::attribute_args::attributes::shorthand::{
     AutoPluginShortHandAttribute, ExpandAttrs, Mode, ShortHandAttribute, tokens,
 };
-use crate::__private::attribute_args::{AutoPluginAttributeKind, GenericsArgs};
+use crate::__private::attribute_args::{AttributeKeys, AutoPluginAttributeKind, GenericsArgs};
 use crate::__private::type_list::TypeList;
 use darling::FromMeta;
 use proc_macro2::{TokenStream as MacroStream, TokenStream};
@@ -18,6 +18,10 @@ pub struct ObserverAttributeArgs {
     pub generics: Vec<TypeList>,
 }
 
+impl AttributeKeys for ObserverAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics"];
+}
+
 impl GenericsArgs for ObserverAttributeArgs {
     fn type_lists(&self) -> &[TypeList] {
         &self.generics
@@ -5,7 +5,7 @@ use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToT
 use crate::__private::attribute_args::attributes::shorthand::{
     AutoPluginShortHandAttribute, ExpandAttrs, Mode, ShortHandAttribute, tokens,
 };
-use crate::__private::attribute_args::{AutoPluginAttributeKind, GenericsArgs};
+use crate::__private::attribute_args::{AttributeKeys, AutoPluginAttributeKind, GenericsArgs};
 use crate::__private::flag_or_list::FlagOrList;
 use crate::__private::non_empty_path::NonEmptyPath;
 use crate::__private::type_list::TypeList;
@@ -25,6 +25,10 @@ pub struct ResourceAttributeArgs {
     pub init: bool,
 }
 
+impl AttributeKeys for ResourceAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics", "derive", "reflect", "register", "init"];
+}
+
 impl GenericsArgs for ResourceAttributeArgs {
     fn type_lists(&self) -> &[TypeList] {
         &self.generics
@@ -47,8 +51,10 @@ impl<'a> From<&'a ResourceAttributeArgs> for RegisterTypeAttributeArgs {
 }
 
 impl<'a> From<&'a ResourceAttributeArgs> for InitResourceAttributeArgs {
-    fn from(_: &'a ResourceAttributeArgs) -> Self {
-        Self::default()
+    fn from(value: &'a ResourceAttributeArgs) -> Self {
+        Self {
+            generics: value.generics.clone(),
+        }
     }
 }
 
@@ -5,7 +5,7 @@ use crate::__private::attribute_args::attributes::shorthand::tokens::ArgsBackToT
 use crate::__private::attribute_args::attributes::shorthand::{
     AutoPluginShortHandAttribute, ExpandAttrs, Mode, ShortHandAttribute, tokens,
 };
-use crate::__private::attribute_args::{AutoPluginAttributeKind, GenericsArgs};
+use crate::__private::attribute_args::{AttributeKeys, AutoPluginAttributeKind, GenericsArgs};
 use crate::__private::flag_or_list::FlagOrList;
 use crate::__private::non_empty_path::NonEmptyPath;
 use crate::__private::type_list::TypeList;
@@ -24,6 +24,10 @@ pub struct StatesAttributeArgs {
     pub init: bool,
 }
 
+impl AttributeKeys for StatesAttributeArgs {
+    const KEYS: &'static [&'static str] = &["generics", "derive", "reflect", "register", "init"];
+}
+
 impl GenericsArgs for StatesAttributeArgs {
     fn type_lists(&self) -> &[TypeList] {
         &self.generics
@@ -6,7 +6,7 @@ use crate::__private::attribute_args::attributes::shorthand::{
     AutoPluginShortHandAttribute, ExpandAttrs, Mode, ShortHandAttribute, tokens,
 };
 use crate::__private::attribute_args::schedule_config::ScheduleWithScheduleConfigArgs;
-use crate::__private::attribute_args::{AutoPluginAttributeKind, GenericsArgs};
+use crate::__private::attribute_args::{AttributeKeys, AutoPluginAttributeKind, GenericsArgs};
 use crate::__private::type_list::TypeList;
 use darling::FromMeta;
 use proc_macro2::{TokenStream as MacroStream, TokenStream};
@@ -21,6 +21,22 @@ pub struct SystemAttributeArgs {
     pub schedule_config: ScheduleWithScheduleConfigArgs,
 }
 
+impl AttributeKeys for SystemAttributeArgs {
+    const KEYS: &'static [&'static str] = &[
+        "generics",
+        "schedule",
+        "config",
+        "pipe",
+        "map",
+        "on_error",
+        "chain",
+        "chain_index",
+        "chain_config",
+    ];
+    const NESTED_KEYS: &'static [(&'static str, &'static [&'static str])] =
+        ScheduleWithScheduleConfigArgs::NESTED_KEYS;
+}
+
 impl GenericsArgs for SystemAttributeArgs {
     fn type_lists(&self) -> &[TypeList] {
         &self.generics
@@ -112,6 +128,21 @@ mod tests {
         Ok(())
     }
 
+    #[internal_test_proc_macro::xtest]
+    fn test_expand_back_into_args_chain() -> syn::Result<()> {
+        let mode = Mode::Global {
+            plugin: parse_quote!(Test),
+        };
+        let args = vec![
+            quote! { schedule = Update },
+            quote! { chain = "movement" },
+            quote! { chain_index = 1 },
+            quote! { chain_config(run_if = ready) },
+        ];
+        assert_vec_args_expand!(mode, SystemAttributeArgs, args);
+        Ok(())
+    }
+
     #[internal_test_proc_macro::xtest]
     fn test_expand_attrs_global() -> syn::Result<()> {
         let args: NestedMeta = parse_quote! {_(
@@ -1,6 +1,7 @@
 pub mod attributes;
 pub mod derives;
 mod schedule_config;
+mod system_adapter;
 
 use crate::__private::attribute::{AutoPluginAttribute, AutoPluginItemAttribute};
 use crate::__private::attribute_args::attributes::shorthand::Mode;
@@ -9,14 +10,20 @@ use crate::__private::attribute_args::attributes::shorthand::tokens::{
 };
 use crate::__private::generics::GenericsCollection;
 use crate::__private::item_with_attr_match::ItemWithAttributeMatch;
+use crate::__private::phase::PluginPhase;
 use crate::__private::type_list::TypeList;
 use crate::__private::util::concrete_path::{
     ConcreteTargetPath, ConcreteTargetPathWithGenericsCollection,
 };
+use crate::__private::util::crate_path::CRATE_KEY;
+use crate::__private::util::debug_expand::DEBUG_EXPAND_FLAG;
 use crate::__private::util::path_fmt::{PathWithoutGenerics, TryFromPathWithoutGenericsError};
 use crate::__private::util::resolve_ident_from_item::IdentFromItemResult;
+use crate::__private::util::tokens::{combine_errors, respan};
 use darling::FromMeta;
+use darling::ast::NestedMeta;
 use proc_macro2::{Ident, TokenStream as MacroStream};
+use quote::{ToTokens, quote};
 use std::hash::Hash;
 use syn::parse::Parse;
 use syn::{Item, Path};
@@ -44,6 +51,124 @@ pub trait ToTokensWithConcreteTargetPath: GenericsArgs {
         self.to_tokens_with_concrete_target_path(&mut tokens, target);
         tokens
     }
+    /// The registration statement on `app` (`&mut App` or `&mut SubApp`) for global registry entries
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &MacroStream,
+        target: &ConcreteTargetPath,
+    ) -> MacroStream {
+        let tokens = self.to_token_stream_with_concrete_target_path(target);
+        quote! { #app #tokens ; }
+    }
+    /// [`Self::register_statement_with_concrete_target_path`] for an entry bound to `plugin`, for args reading its instance
+    fn register_statement_for_plugin(
+        &self,
+        app: &MacroStream,
+        target: &ConcreteTargetPath,
+        _plugin: &Path,
+    ) -> MacroStream {
+        self.register_statement_with_concrete_target_path(app, target)
+    }
+}
+
+/// `checks::#register_fn::<target>(app, ..)`, registering through the global mode `checks` fn named `register_fn`
+/// so a target missing an impl fails to compile there, spanned to the attribute, and nowhere else
+pub fn checked_register_statement(
+    register_fn: &str,
+    target: &ConcreteTargetPath,
+    app: &MacroStream,
+    args: &[MacroStream],
+) -> MacroStream {
+    let register_fn = quote::format_ident!("{register_fn}");
+    let target = respan(target.to_token_stream(), proc_macro2::Span::call_site());
+    quote! {
+        ::bevy_auto_plugin::__private::shared::__private::modes::global::checks::#register_fn::<#target>(#app #(, #args)*);
+    }
+}
+
+/// `checks::#check_fn(target)`, passing a system or observer through the global mode `checks` fn named `check_fn`,
+/// see [`checked_register_statement`]
+pub fn checked_target(check_fn: &str, target: &ConcreteTargetPath) -> MacroStream {
+    let check_fn = quote::format_ident!("{check_fn}");
+    let target = respan(target.to_token_stream(), proc_macro2::Span::call_site());
+    quote! {
+        ::bevy_auto_plugin::__private::shared::__private::modes::global::checks::#check_fn(#target)
+    }
+}
+
+/// Keys accepted inside an attribute's parentheses, listed when an unknown key is given
+pub trait AttributeKeys {
+    const KEYS: &'static [&'static str];
+    /// Keys of nested lists, e.g. `config(..)`
+    const NESTED_KEYS: &'static [(&'static str, &'static [&'static str])] = &[];
+}
+
+/// Keys every global attribute accepts on top of its own, see [`GlobalArgs`]
+pub const GLOBAL_KEYS: &[&str] = &["plugin", "phase", "sub_app", "group"];
+
+/// Keys every attribute accepts in every mode, taken out of the args before they're parsed,
+/// see [`crate_path`](crate::__private::util::crate_path) and [`debug_expand`](crate::__private::util::debug_expand)
+pub const FORWARDED_KEYS: &[&str] = &[CRATE_KEY, DEBUG_EXPAND_FLAG];
+
+/// Parses attribute args like `derive_syn_parse` does, listing the keys `K` accepts on unknown ones,
+/// see [`with_valid_keys`]
+pub fn parse_args_with_valid_keys<T: FromMeta, K: AttributeKeys>(
+    attr: MacroStream,
+    attribute: &str,
+    mode: &str,
+    mode_keys: &[&str],
+) -> syn::Result<T> {
+    NestedMeta::parse_meta_list(attr)
+        .map_err(darling::Error::from)
+        .and_then(|list| T::from_list(&list))
+        .map_err(|err| with_valid_keys::<K>(err, attribute, mode, mode_keys))
+}
+
+/// Lists the keys `attribute` accepts in `mode` under darling's "Unknown field" errors in `err`
+pub fn with_valid_keys<T: AttributeKeys>(
+    err: darling::Error,
+    attribute: &str,
+    mode: &str,
+    mode_keys: &[&str],
+) -> syn::Error {
+    fn list(keys: &[&str]) -> String {
+        keys.iter()
+            .map(|key| format!("`{key}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+    combine_errors(err.flatten().into_iter().map(|err| {
+        // only the darling error knows which nested list (e.g. `config`) the key was in
+        let located = err.to_string();
+        let err = syn::Error::from(err);
+        let message = err.to_string();
+        if !message.starts_with("Unknown field") {
+            return err;
+        }
+        let nested = T::NESTED_KEYS
+            .iter()
+            .find(|(nested, _)| located.ends_with(&format!(" at {nested}")));
+        let valid = match nested {
+            Some((nested, keys)) => {
+                format!("valid keys for `{attribute}({nested}(..))`: {}", list(keys))
+            }
+            None => {
+                let mut keys = [mode_keys, T::KEYS, FORWARDED_KEYS].concat();
+                // expanded into `generics(..)` before parsing, global mode only
+                if mode == "global"
+                    && let Some(ix) = keys.iter().position(|key| *key == "generics")
+                {
+                    keys.splice(ix + 1..ix + 1, ["generics_set", "generics_product"]);
+                }
+                format!(
+                    "valid keys for `{attribute}` in {mode} mode: {}",
+                    list(&keys)
+                )
+            }
+        };
+        syn::Error::new(err.span(), format!("{message}\n{valid}"))
+    }))
+    .expect("darling::Error always holds at least one error")
 }
 
 pub trait AutoPluginAttributeKind {
@@ -53,6 +178,7 @@ pub trait AutoPluginAttributeKind {
 
 pub trait ItemAttributeArgs:
     AutoPluginAttributeKind<Attribute = AutoPluginItemAttribute>
+    + AttributeKeys
     + FromMeta
     + Parse
     + ToTokensWithConcreteTargetPath
@@ -62,6 +188,10 @@ pub trait ItemAttributeArgs:
     fn global_build_prefix() -> &'static str;
     fn resolve_item_ident(item: &Item) -> IdentFromItemResult<'_>;
     fn match_items(items: &[Item]) -> syn::Result<Vec<ItemWithAttributeMatch<'_, Self>>>;
+    /// Declared schedule and ordering constraints as `(arg, path)` pairs, recorded on global registry entries
+    fn ordering(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
 }
 
 pub trait GlobalAttributeArgs:
@@ -70,6 +200,9 @@ pub trait GlobalAttributeArgs:
     type Inner: ItemAttributeArgs;
     fn inner(&self) -> &Self::Inner;
     fn plugin(&self) -> &Path;
+    fn phase(&self) -> PluginPhase;
+    fn sub_app(&self) -> Option<&Path>;
+    fn group(&self) -> Option<&str>;
 
     fn _concat_ident_hash(&self, ident: &Ident) -> String {
         use std::hash::{Hash, Hasher};
@@ -120,6 +253,17 @@ impl<T: ToTokensWithConcreteTargetPath + Clone> WithTargetPath<T> {
                     .to_token_stream_with_concrete_target_path(&concrete_target_path)
             })
     }
+    pub fn register_statements_iter<'a>(
+        &'a self,
+        app: &'a MacroStream,
+    ) -> impl Iterator<Item = MacroStream> + 'a {
+        self.concrete_target_paths()
+            .into_iter()
+            .map(move |concrete_target_path| {
+                self.inner
+                    .register_statement_with_concrete_target_path(app, &concrete_target_path)
+            })
+    }
 }
 
 impl<T> From<(PathWithoutGenerics, T)> for WithTargetPath<T> {
@@ -148,6 +292,12 @@ impl<T> TryFrom<(Path, T)> for WithTargetPath<T> {
 #[darling(derive_syn_parse)]
 pub struct GlobalArgs<T> {
     pub plugin: Path,
+    #[darling(default)]
+    pub phase: PluginPhase,
+    #[darling(default)]
+    pub sub_app: Option<Path>,
+    #[darling(default)]
+    pub group: Option<String>,
     #[darling(flatten)]
     pub inner: T,
 }
@@ -185,6 +335,14 @@ where
         self.inner
             .to_tokens_with_concrete_target_path(tokens, target)
     }
+    fn register_statement_with_concrete_target_path(
+        &self,
+        app: &MacroStream,
+        target: &ConcreteTargetPath,
+    ) -> MacroStream {
+        self.inner
+            .register_statement_for_plugin(app, target, &self.plugin)
+    }
 }
 
 impl<T> GlobalAttributeArgs for GlobalArgs<T>
@@ -198,4 +356,232 @@ where
     fn plugin(&self) -> &Path {
         &self.plugin
     }
+    fn phase(&self) -> PluginPhase {
+        self.phase
+    }
+    fn sub_app(&self) -> Option<&Path> {
+        self.sub_app.as_ref()
+    }
+    fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::__private::attribute_args::attributes::modes::global::auto_plugin::{
+        AutoPluginFnAttributeArgs, AutoPluginStructOrEnumAttributeArgs,
+    };
+    use crate::__private::attribute_args::attributes::prelude::*;
+    use crate::__private::attribute_args::attributes::shorthand::prelude::*;
+    use crate::__private::attribute_args::schedule_config::{
+        ScheduleConfigArgs, ScheduleWithScheduleConfigArgs,
+    };
+    use quote::{ToTokens, format_ident, quote};
+    use std::collections::{BTreeSet, HashMap};
+    use std::path::Path as FsPath;
+
+    /// Fields of every `FromMeta` struct under `attribute_args`, with the types of their `flatten` fields
+    fn darling_structs() -> HashMap<String, (Vec<String>, Vec<String>)> {
+        fn visit(dir: &FsPath, structs: &mut HashMap<String, (Vec<String>, Vec<String>)>) {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    visit(&path, structs);
+                    continue;
+                }
+                let file = syn::parse_file(&std::fs::read_to_string(&path).unwrap()).unwrap();
+                for item in file.items {
+                    let Item::Struct(item) = item else { continue };
+                    let derives_from_meta = item.attrs.iter().any(|attr| {
+                        attr.path().is_ident("derive")
+                            && attr.meta.to_token_stream().to_string().contains("FromMeta")
+                    });
+                    if !derives_from_meta {
+                        continue;
+                    }
+                    let generic_params = item
+                        .generics
+                        .type_params()
+                        .map(|param| param.ident.to_string())
+                        .collect::<Vec<_>>();
+                    let (mut fields, mut flattened) = (vec![], vec![]);
+                    for field in &item.fields {
+                        let is_flatten = field.attrs.iter().any(|attr| {
+                            attr.path().is_ident("darling")
+                                && attr.meta.to_token_stream().to_string().contains("flatten")
+                        });
+                        if !is_flatten {
+                            fields.push(field.ident.as_ref().unwrap().to_string());
+                            continue;
+                        }
+                        let syn::Type::Path(ty) = &field.ty else {
+                            panic!("unexpected flatten type in {}", item.ident);
+                        };
+                        let ty = ty.path.segments.last().unwrap().ident.to_string();
+                        // `GlobalArgs<T>` flattens whatever attribute it wraps
+                        if !generic_params.contains(&ty) {
+                            flattened.push(ty);
+                        }
+                    }
+                    structs.insert(item.ident.to_string(), (fields, flattened));
+                }
+            }
+        }
+        let mut structs = HashMap::new();
+        visit(
+            &FsPath::new(env!("CARGO_MANIFEST_DIR")).join("src/__private/attribute_args"),
+            &mut structs,
+        );
+        structs
+    }
+
+    /// Keys darling accepts for the struct `name`, following its `flatten` fields
+    fn darling_keys(
+        structs: &HashMap<String, (Vec<String>, Vec<String>)>,
+        name: &str,
+    ) -> BTreeSet<String> {
+        let (fields, flattened) = structs
+            .get(name)
+            .unwrap_or_else(|| panic!("no `FromMeta` struct named `{name}`"));
+        let mut keys = fields.iter().cloned().collect::<BTreeSet<_>>();
+        for flattened in flattened {
+            keys.extend(darling_keys(structs, flattened));
+        }
+        keys
+    }
+
+    fn type_name<T>() -> &'static str {
+        std::any::type_name::<T>().rsplit("::").next().unwrap()
+    }
+
+    /// The listed keys have to be exactly the fields darling parses, otherwise the list drifted
+    fn assert_keys_match_fields<T: AttributeKeys>(
+        structs: &HashMap<String, (Vec<String>, Vec<String>)>,
+    ) {
+        let name = type_name::<T>();
+        let keys = T::KEYS.iter().map(|key| key.to_string()).collect();
+        assert_eq!(darling_keys(structs, name), keys, "{name}::KEYS");
+        for key in FORWARDED_KEYS {
+            assert!(
+                !keys.contains(*key),
+                "{name}: `{key}` is taken out before parsing and never reaches darling"
+            );
+        }
+    }
+
+    /// Every listed key has to be one darling knows, otherwise the list drifted from the fields
+    fn assert_keys_known<T: FromMeta + AttributeKeys>() {
+        assert_keys_match_fields::<T>(&darling_structs());
+        let known = |attr: MacroStream| match parse_args_with_valid_keys::<T, T>(
+            attr.clone(),
+            "test",
+            "test",
+            &[],
+        ) {
+            Ok(_) => {}
+            Err(err) => {
+                for err in err {
+                    assert!(
+                        !err.to_string().starts_with("Unknown field"),
+                        "{}: `{attr}` {err}",
+                        std::any::type_name::<T>()
+                    );
+                }
+            }
+        };
+        for key in T::KEYS {
+            let key = format_ident!("{key}");
+            known(quote!(#key));
+        }
+        for (nested, keys) in T::NESTED_KEYS {
+            let nested = format_ident!("{nested}");
+            for key in *keys {
+                let key = format_ident!("{key}");
+                known(quote!(#nested(#key)));
+            }
+        }
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_attribute_keys_known() {
+        assert_keys_known::<AddEventAttributeArgs>();
+        assert_keys_known::<AddObserverAttributeArgs>();
+        assert_keys_known::<AddSystemAttributeArgs>();
+        assert_keys_known::<AutoNameAttributeArgs>();
+        assert_keys_known::<InitResourceAttributeArgs>();
+        assert_keys_known::<InitStateAttributeArgs>();
+        assert_keys_known::<InsertResourceAttributeArgs>();
+        assert_keys_known::<RegisterStateTypeAttributeArgs>();
+        assert_keys_known::<RegisterTypeAttributeArgs>();
+        assert_keys_known::<ComponentAttributeArgs>();
+        assert_keys_known::<EventAttributeArgs>();
+        assert_keys_known::<ObserverAttributeArgs>();
+        assert_keys_known::<ResourceAttributeArgs>();
+        assert_keys_known::<StatesAttributeArgs>();
+        assert_keys_known::<SystemAttributeArgs>();
+        assert_keys_known::<AutoPluginStructOrEnumAttributeArgs>();
+        assert_keys_known::<AutoPluginFnAttributeArgs>();
+        assert_keys_known::<ScheduleWithScheduleConfigArgs>();
+        assert_keys_known::<ScheduleConfigArgs>();
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_global_keys_match_fields() {
+        let structs = darling_structs();
+        let keys = GLOBAL_KEYS.iter().map(|key| key.to_string()).collect();
+        assert_eq!(darling_keys(&structs, "GlobalArgs"), keys);
+        for key in FORWARDED_KEYS {
+            assert!(!keys.contains(*key), "`{key}` is taken out before parsing");
+        }
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_with_valid_keys() {
+        let err = parse_args_with_valid_keys::<
+            GlobalArgs<ComponentAttributeArgs>,
+            ComponentAttributeArgs,
+        >(
+            quote!(plugin = Test, regiser),
+            "auto_component",
+            "global",
+            GLOBAL_KEYS,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown field: `regiser`. Did you mean `register`?\nvalid keys for `auto_component` in global mode: `plugin`, `phase`, `sub_app`, `group`, `generics`, `generics_set`, `generics_product`, `derive`, `reflect`, `register`, `auto_name`, `crate`, `debug_expand`"
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_checked_register_statement() -> syn::Result<()> {
+        let target = ConcreteTargetPath {
+            target: syn::parse2::<PathWithoutGenerics>(quote! { Foo })?,
+            generics: crate::__private::generics::Generics(syn::parse2(quote! { u8 })?),
+            turbofish: false,
+        };
+        assert_eq!(
+            checked_register_statement("insert_resource", &target, &quote! { app }, &[quote! { Foo(1) }])
+                .to_string(),
+            quote! {
+                ::bevy_auto_plugin::__private::shared::__private::modes::global::checks::insert_resource::<Foo<u8> >(app, Foo(1));
+            }
+            .to_string()
+        );
+        let system = ConcreteTargetPath {
+            target: syn::parse2::<PathWithoutGenerics>(quote! { foo })?,
+            turbofish: true,
+            ..target
+        };
+        assert_eq!(
+            checked_target("add_system_target", &system).to_string(),
+            quote! {
+                ::bevy_auto_plugin::__private::shared::__private::modes::global::checks::add_system_target(foo::<u8>)
+            }
+            .to_string()
+        );
+        Ok(())
+    }
 }
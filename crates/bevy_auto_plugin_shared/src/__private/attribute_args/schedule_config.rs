@@ -1,17 +1,91 @@
+use crate::__private::attribute_args::AttributeKeys;
+use crate::__private::attribute_args::system_adapter::SystemAdapterArgs;
 use darling::FromMeta;
 use proc_macro2::TokenStream as MacroStream;
 use quote::{ToTokens, quote};
-use syn::Path;
+use syn::{LitStr, Path};
 
 #[derive(FromMeta, Clone, Debug, PartialEq, Hash)]
-#[darling(derive_syn_parse)]
+#[darling(derive_syn_parse, and_then = Self::validate_chain)]
 pub struct ScheduleWithScheduleConfigArgs {
     pub schedule: Path,
     #[darling(default)]
     pub config: ScheduleConfigArgs,
+    #[darling(flatten)]
+    pub adapters: SystemAdapterArgs,
+    /// `chain = "name"`, runs after the members of the chain with a lower `chain_index`
+    pub chain: Option<LitStr>,
+    pub chain_index: Option<u32>,
+    /// Config for the whole chain, applied to its `SystemChain` set
+    pub chain_config: Option<ScheduleConfigArgs>,
+}
+
+impl AttributeKeys for ScheduleWithScheduleConfigArgs {
+    const KEYS: &'static [&'static str] = &[
+        "schedule",
+        "config",
+        "pipe",
+        "map",
+        "on_error",
+        "chain",
+        "chain_index",
+        "chain_config",
+    ];
+    const NESTED_KEYS: &'static [(&'static str, &'static [&'static str])] = &[
+        ("config", ScheduleConfigArgs::KEYS),
+        ("chain_config", ScheduleConfigArgs::KEYS),
+    ];
 }
 
 impl ScheduleWithScheduleConfigArgs {
+    fn validate_chain(self) -> darling::Result<Self> {
+        match (&self.chain, self.chain_index, &self.chain_config) {
+            (Some(_), Some(_), _) | (None, None, None) => Ok(self),
+            (Some(chain), None, _) => Err(darling::Error::custom(
+                "`chain` requires a `chain_index` to order the system in the chain",
+            )
+            .with_span(chain)),
+            (None, _, _) => Err(darling::Error::custom(
+                "`chain_index` and `chain_config(..)` require `chain = \"name\"`",
+            )),
+        }
+    }
+
+    /// Only global mode registers chained systems
+    pub fn validate_no_chain(&self) -> syn::Result<()> {
+        match &self.chain {
+            Some(chain) => Err(syn::Error::new(
+                chain.span(),
+                "`chain` is only supported in global mode",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The schedule and ordering constraints as `(arg, path)` pairs, e.g. `("after", "foo")`
+    pub fn ordering(&self) -> Vec<(&'static str, String)> {
+        let config = &self.config;
+        [
+            ("schedule", Some(&self.schedule)),
+            ("in_set", config.in_set.as_ref()),
+            ("before", config.before.as_ref()),
+            ("after", config.after.as_ref()),
+            (
+                "before_ignore_deferred",
+                config.before_ignore_deferred.as_ref(),
+            ),
+            (
+                "after_ignore_deferred",
+                config.after_ignore_deferred.as_ref(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(arg, path)| {
+            let path = path?.to_token_stream().to_string().replace(' ', "");
+            Some((arg, path))
+        })
+        .collect()
+    }
     pub fn to_inner_arg_tokens_vec(&self) -> Vec<MacroStream> {
         let mut tokens = vec![];
         let schedule = &self.schedule;
@@ -20,6 +94,18 @@ impl ScheduleWithScheduleConfigArgs {
         if !config.is_empty() {
             tokens.push(quote! { config( #(#config),* )});
         }
+        tokens.extend(self.adapters.to_inner_arg_tokens_vec());
+        if let Some(chain) = &self.chain {
+            tokens.push(quote! { chain = #chain });
+        }
+        if let Some(chain_index) = self.chain_index {
+            let chain_index = proc_macro2::Literal::u32_unsuffixed(chain_index);
+            tokens.push(quote! { chain_index = #chain_index });
+        }
+        if let Some(chain_config) = &self.chain_config {
+            let chain_config = chain_config.to_inner_arg_tokens_vec();
+            tokens.push(quote! { chain_config( #(#chain_config),* )});
+        }
         tokens
     }
 }
@@ -38,6 +124,20 @@ pub struct ScheduleConfigArgs {
     pub before_ignore_deferred: Option<Path>,
 }
 
+impl AttributeKeys for ScheduleConfigArgs {
+    const KEYS: &'static [&'static str] = &[
+        "in_set",
+        "before",
+        "after",
+        "run_if",
+        "distributive_run_if",
+        "ambiguous_with",
+        "ambiguous_with_all",
+        "after_ignore_deferred",
+        "before_ignore_deferred",
+    ];
+}
+
 impl ScheduleConfigArgs {
     pub fn to_inner_arg_tokens_vec(&self) -> Vec<MacroStream> {
         let mut tokens = vec![];
@@ -0,0 +1,81 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{ToTokens, quote};
+use syn::Expr;
+
+/// Adapters wrapping a system before its schedule config is applied,
+/// in the order `map`, `pipe`, `on_error`
+#[derive(FromMeta, Clone, Debug, Default, PartialEq, Hash)]
+#[darling(derive_syn_parse, default)]
+pub struct SystemAdapterArgs {
+    /// `system.map(f)`
+    pub map: Option<Expr>,
+    /// `system.pipe(other)`
+    pub pipe: Option<Expr>,
+    /// Calls `handler(err)` when the system returns an `Err`
+    pub on_error: Option<Expr>,
+}
+
+impl SystemAdapterArgs {
+    /// `system` wrapped in the adapters, calling `IntoSystem` by path so it doesn't need to be in scope
+    pub fn wrap(&self, system: impl ToTokens) -> MacroStream {
+        let into_system = quote! { ::bevy_auto_plugin::__private::shared::__private::bevy_ecs::system::IntoSystem };
+        let mut system = system.to_token_stream();
+        if let Some(map) = &self.map {
+            system = quote! { #into_system::map(#system, #map) };
+        }
+        if let Some(pipe) = &self.pipe {
+            system = quote! { #into_system::pipe(#system, #pipe) };
+        }
+        if let Some(on_error) = &self.on_error {
+            system = quote! {
+                #into_system::map(#system, |result| {
+                    if let ::core::result::Result::Err(err) = result {
+                        (#on_error)(err)
+                    }
+                })
+            };
+        }
+        system
+    }
+
+    pub fn to_inner_arg_tokens_vec(&self) -> Vec<MacroStream> {
+        let mut tokens = vec![];
+        if let Some(map) = &self.map {
+            tokens.push(quote! { map = #map });
+        }
+        if let Some(pipe) = &self.pipe {
+            tokens.push(quote! { pipe = #pipe });
+        }
+        if let Some(on_error) = &self.on_error {
+            tokens.push(quote! { on_error = #on_error });
+        }
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use darling::ast::NestedMeta;
+    use syn::parse_quote;
+
+    #[internal_test_proc_macro::xtest]
+    fn test_wrap() -> darling::Result<()> {
+        let args: NestedMeta = parse_quote! {_(pipe = log_result, map = double)};
+        let args = SystemAdapterArgs::from_nested_meta(&args)?;
+        let into_system = quote! { ::bevy_auto_plugin::__private::shared::__private::bevy_ecs::system::IntoSystem };
+        assert_eq!(
+            args.wrap(quote! { foo::<u8> }).to_string(),
+            quote! { #into_system::pipe(#into_system::map(foo::<u8>, double), log_result) }
+                .to_string()
+        );
+        assert_eq!(
+            SystemAdapterArgs::default()
+                .wrap(quote! { foo })
+                .to_string(),
+            quote! { foo }.to_string()
+        );
+        Ok(())
+    }
+}
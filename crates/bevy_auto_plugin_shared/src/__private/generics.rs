@@ -7,6 +7,26 @@ use quote::{ToTokens, quote};
 pub struct GenericsCollection(pub Vec<TypeList>);
 
 impl GenericsCollection {
+    /// Every combination of one arg per parameter, the first parameter varying slowest
+    pub fn product(params: &[Vec<syn::GenericArgument>]) -> Self {
+        let mut lists = vec![TypeList::empty()];
+        for types in params {
+            lists = lists
+                .into_iter()
+                .flat_map(|list| {
+                    types.iter().map(move |ty| {
+                        let mut list = list.clone();
+                        list.0.push(ty.clone());
+                        list
+                    })
+                })
+                .collect();
+        }
+        if params.is_empty() {
+            lists.clear();
+        }
+        Self(lists)
+    }
     pub fn iter_with_default_generics_when_empty(self) -> impl IntoIterator<Item = Generics> {
         let mut vec = self.0.into_iter().map(Generics).collect::<Vec<_>>();
 
@@ -87,7 +107,10 @@ mod tests {
     fn types() -> syn::Result<TypeList> {
         let ty_u32 = Type::Path(TypePath::from_string("u32")?);
         let ty_bool = Type::Path(TypePath::from_string("bool")?);
-        Ok(TypeList(vec![ty_u32, ty_bool]))
+        Ok(TypeList(vec![
+            syn::GenericArgument::Type(ty_u32),
+            syn::GenericArgument::Type(ty_bool),
+        ]))
     }
 
     #[internal_test_proc_macro::xtest]
@@ -107,6 +130,27 @@ mod tests {
         Ok(())
     }
 
+    #[internal_test_proc_macro::xtest]
+    fn test_generics_collection_product() {
+        let params: Vec<Vec<syn::GenericArgument>> = vec![
+            vec![syn::parse_quote!(u8), syn::parse_quote!(f32)],
+            vec![syn::parse_quote!(bool), syn::parse_quote!(())],
+        ];
+        assert_eq!(
+            GenericsCollection::product(&params)
+                .to_attribute_arg_tokens()
+                .to_string(),
+            quote!(
+                generics(u8, bool),
+                generics(u8, ()),
+                generics(f32, bool),
+                generics(f32, ())
+            )
+            .to_string()
+        );
+        assert!(GenericsCollection::product(&[]).is_empty());
+    }
+
     #[internal_test_proc_macro::xtest]
     fn test_generics_collection() -> syn::Result<()> {
         let generics = GenericsCollection(vec![types()?]);
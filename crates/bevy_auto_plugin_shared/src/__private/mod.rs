@@ -8,6 +8,7 @@ pub mod item_with_attr_match;
 mod macros;
 pub mod modes;
 mod non_empty_path;
+pub mod phase;
 mod type_list;
 pub mod util;
 
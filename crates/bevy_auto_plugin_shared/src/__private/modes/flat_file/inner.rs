@@ -1,9 +1,9 @@
-use crate::__private::attribute_args::ItemAttributeArgs;
 use crate::__private::attribute_args::attributes::modes::flat_file::auto_plugin::AutoPluginArgs;
 use crate::__private::attribute_args::attributes::modes::resolve_app_param_name;
 use crate::__private::attribute_args::attributes::prelude::*;
 use crate::__private::attribute_args::attributes::shorthand::ShortHandAttribute;
 use crate::__private::attribute_args::attributes::shorthand::prelude::*;
+use crate::__private::attribute_args::{ItemAttributeArgs, parse_args_with_valid_keys};
 use crate::__private::context::{
     AutoPluginContextInsert, SupportsAutoPluginContextInsert, ToTokenStringValue,
 };
@@ -17,7 +17,7 @@ use crate::__private::util::meta::fn_meta::FnMeta;
 use crate::__private::util::meta::struct_or_enum_meta::StructOrEnumMeta;
 use crate::__private::util::path_fmt::PathWithoutGenerics;
 use crate::__private::util::tokens::to_compile_error;
-use crate::{ok_or_return_compiler_error, parse_macro_input2};
+use crate::parse_macro_input2;
 use darling::FromMeta;
 use darling::ast::NestedMeta;
 use proc_macro2::{Ident, Span, TokenStream as MacroStream};
@@ -259,6 +259,7 @@ pub fn handle_add_system_attribute_inner(
     args: AddSystemAttributeArgs,
     attr_span: Span,
 ) -> syn::Result<()> {
+    args.schedule_config.validate_no_chain()?;
     let path_without_generics = PathWithoutGenerics::from(item.sig.ident);
     let concrete_target_paths =
         ConcreteTargetPathWithGenericsCollection::from_args(path_without_generics, &args);
@@ -298,7 +299,11 @@ where
         Err(err) => return err.to_compile_error(),
     };
 
-    let args = ok_or_return_compiler_error!(parse2::<T>(attr));
+    let attribute = T::attribute().ident_str();
+    let args = match parse_args_with_valid_keys::<T, T>(attr, attribute, "flat_file", &[]) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error(),
+    };
 
     handle_attribute_outer(parsed_item, Span::call_site(), args)
         .map(|_| cloned_input)
@@ -0,0 +1,118 @@
+use super::{
+    AutoPluginEntryKind, BuiltAutoPlugins, EntrySource, GlobalAutoPluginRegistryEntryFactory,
+    global_registry,
+};
+use crate::__private::phase::PluginPhase;
+use bevy_app::{App, Plugin};
+use std::any::TypeId;
+use std::fmt::{Display, Formatter};
+
+/// A registry entry bound to a plugin that was never added to the [`App`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedEntry {
+    pub plugin: TypeId,
+    pub kind: AutoPluginEntryKind,
+    pub phase: PluginPhase,
+    pub group: Option<&'static str>,
+    pub source: Option<EntrySource>,
+}
+
+impl Display for OrphanedEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "`{}` {} at {source}", source.plugin, self.kind.as_str()),
+            None => write!(
+                f,
+                "{:?} {} at unknown location",
+                self.plugin,
+                self.kind.as_str()
+            ),
+        }
+    }
+}
+
+/// Entries in the global registry whose plugin hasn't been built in `app`
+pub fn orphaned_entries(app: &App) -> Vec<OrphanedEntry> {
+    let built = app.world().get_resource::<BuiltAutoPlugins>();
+    let mut orphans = global_registry()
+        .iter()
+        .filter(|(type_id, _, _)| !built.is_some_and(|built| built.contains(*type_id)))
+        .flat_map(|(plugin, _, factories)| {
+            factories.map(move |factory| OrphanedEntry::new(plugin, factory))
+        })
+        .collect::<Vec<_>>();
+    sort_by_source(&mut orphans);
+    orphans
+}
+
+impl OrphanedEntry {
+    fn new(plugin: TypeId, factory: &GlobalAutoPluginRegistryEntryFactory) -> Self {
+        Self {
+            plugin,
+            kind: factory.kind(),
+            phase: factory.phase(),
+            group: factory.group(),
+            source: factory.source(),
+        }
+    }
+}
+
+fn sort_by_source(entries: &mut [OrphanedEntry]) {
+    entries.sort_by_key(|entry| {
+        entry
+            .source
+            .map(|source| (source.file, source.line, source.column))
+    });
+}
+
+/// What [`AutoPluginAuditPlugin`] does when it finds orphaned entries
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    /// Log a warning listing the orphaned entries
+    #[default]
+    Warn,
+    /// Panic listing the orphaned entries
+    Panic,
+}
+
+/// Reports entries bound to an `AutoPlugin` that was never added to the [`App`],
+/// e.g. `#[auto_component(plugin = AudioPlugin)]` without `app.add_plugins(AudioPlugin)`.
+///
+/// The check runs once every plugin is built (in [`Plugin::cleanup`]).
+/// It covers every entry linked into the binary, so plugins that are only added to other apps are reported too.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AutoPluginAuditPlugin {
+    pub action: AuditAction,
+}
+
+impl AutoPluginAuditPlugin {
+    /// Panics instead of warning when orphaned entries are found
+    pub fn panicking() -> Self {
+        Self {
+            action: AuditAction::Panic,
+        }
+    }
+}
+
+impl Plugin for AutoPluginAuditPlugin {
+    fn build(&self, _app: &mut App) {}
+
+    fn cleanup(&self, app: &mut App) {
+        let orphans = orphaned_entries(app);
+        if orphans.is_empty() {
+            return;
+        }
+        let report = orphans
+            .iter()
+            .map(|orphan| format!("\n  - {orphan}"))
+            .collect::<String>();
+        let message = format!(
+            "{} auto_plugin entries are bound to plugins that were never added to the App:{report}",
+            orphans.len()
+        );
+        match self.action {
+            AuditAction::Warn => bevy_log::warn!("{message}"),
+            AuditAction::Panic => panic!("{message}"),
+        }
+    }
+}
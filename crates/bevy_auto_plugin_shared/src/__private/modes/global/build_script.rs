@@ -0,0 +1,182 @@
+//! Registry entries listed by a `bevy_auto_plugin_build` generated `auto_plugins.rs` instead of linker sections.
+//!
+//! `bevy_auto_plugin_build::generate()` sets [`ENTRIES_FILE_ENV`] for the crate it runs for, and only while it's set
+//! the macros also implement [`BuildScriptEntry`] for the crate's `__BevyAutoPluginEntries` (defined by
+//! [`include_auto_plugins!`](crate::include_auto_plugins)). Crates without a build script are untouched.
+//!
+//! Those entries are plain statics outside the linker sections. A crate's [`BuildScriptEntries`] are added to the
+//! registry the first time one of its plugins is built, or when they're added to the app as a plugin, which is
+//! required for entries bound to another crate's plugin. With the `build_script` feature nothing uses the
+//! `linkme`/`inventory` sections, for targets that don't support them.
+
+use super::{BuiltAutoPlugins, GlobalAutoPluginRegistry, GlobalAutoPluginRegistryEntryFactory};
+use bevy_app::{App, Plugin};
+use std::sync::{Mutex, RwLock};
+
+/// Env var `bevy_auto_plugin_build::generate()` sets to the path of the generated `auto_plugins.rs`
+pub const ENTRIES_FILE_ENV: &str = "BEVY_AUTO_PLUGIN_ENTRIES_FILE";
+
+/// Whether the crate being expanded generates its entries from `build.rs`. Never while `bevy_auto_plugin_build`
+/// expands the sources itself, which looks for the `_plugin_entry!` invocations
+pub fn _entries_file_enabled() -> bool {
+    extern crate proc_macro;
+    proc_macro::is_available() && std::env::var_os(ENTRIES_FILE_ENV).is_some()
+}
+
+/// Implemented by the macros for the entry with the id [`entry_id`]
+pub trait BuildScriptEntry<const ID: u64> {
+    const ENTRY: &'static GlobalAutoPluginRegistryEntryFactory;
+}
+
+/// Implemented by the generated file for every entry it lists
+#[diagnostic::on_unimplemented(
+    message = "entry isn't listed in the generated `auto_plugins.rs`",
+    label = "not found by `bevy_auto_plugin_build`",
+    note = "`bevy_auto_plugin_build` only sees attributes written in the crate's source files, not ones produced by other macros (e.g. `macro_rules!`) or behind `cfg_attr`"
+)]
+pub trait ListedBuildScriptEntry<const ID: u64> {}
+
+/// Fails to compile when the entry `ID` isn't listed in the generated file of the crate `T`
+pub const fn _require_listed<T: ListedBuildScriptEntry<ID>, const ID: u64>() {}
+
+/// The generated fn listing every entry of a crate
+pub type BuildScriptEntriesFn = fn(&mut dyn FnMut(&'static GlobalAutoPluginRegistryEntryFactory));
+
+/// FNV-1a hash of `path` (`<module path>::<entry ident>`) without its first segment,
+/// which is the crate name for `module_path!()` and `crate` in generated files
+pub const fn entry_id(path: &str) -> u64 {
+    let bytes = path.as_bytes();
+    let mut start = 0;
+    while start + 1 < bytes.len() && !(bytes[start] == b':' && bytes[start + 1] == b':') {
+        start += 1;
+    }
+    start += 2;
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut ix = start;
+    while ix < bytes.len() {
+        hash ^= bytes[ix] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        ix += 1;
+    }
+    hash
+}
+
+/// The entries of a crate, `AUTO_PLUGIN_ENTRIES` in its generated `auto_plugins.rs`.
+///
+/// Added to the registry when one of the crate's plugins is built. Nothing else finds them, so entries bound to
+/// another crate's plugin only run if they're added as a plugin before that plugin is built:
+/// `app.add_plugins((my_crate::AUTO_PLUGIN_ENTRIES, OtherCratePlugin))`
+#[derive(Debug, Clone, Copy)]
+pub struct BuildScriptEntries {
+    crate_name: &'static str,
+    entries: BuildScriptEntriesFn,
+}
+
+impl BuildScriptEntries {
+    pub const fn new(crate_name: &'static str, entries: BuildScriptEntriesFn) -> Self {
+        Self {
+            crate_name,
+            entries,
+        }
+    }
+    pub const fn crate_name(&self) -> &'static str {
+        self.crate_name
+    }
+    /// The entries listed for the crate
+    pub fn factories(&self) -> Vec<&'static GlobalAutoPluginRegistryEntryFactory> {
+        let mut factories = Vec::new();
+        (self.entries)(&mut |factory| factories.push(factory));
+        factories
+    }
+    /// Adds the entries to the registry, once per crate
+    pub fn register(&self) {
+        let mut sources = SOURCES.lock().unwrap_or_else(|err| err.into_inner());
+        if sources
+            .iter()
+            .any(|source| source.crate_name == self.crate_name)
+        {
+            return;
+        }
+        sources.push(*self);
+        bevy_log::debug!(
+            "adding the generated entries of `{}` to the registry",
+            self.crate_name
+        );
+        let listed = sources.iter().flat_map(BuildScriptEntries::factories);
+        #[cfg(not(feature = "build_script"))]
+        let factories = super::section_factories().chain(listed);
+        #[cfg(feature = "build_script")]
+        let factories = listed;
+        // rebuilt once per crate, so the previous registry is leaked like the `LazyLock` one would be
+        let registry = Box::leak(Box::new(GlobalAutoPluginRegistry::from_factories(
+            factories,
+        )));
+        *REGISTRY.write().unwrap_or_else(|err| err.into_inner()) = Some(registry);
+    }
+}
+
+impl Plugin for BuildScriptEntries {
+    fn build(&self, app: &mut App) {
+        self.register();
+        warn_late_entries(&mut app.world_mut().get_resource_or_init::<BuiltAutoPlugins>());
+    }
+    fn name(&self) -> &str {
+        self.crate_name
+    }
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+static SOURCES: Mutex<Vec<BuildScriptEntries>> = Mutex::new(Vec::new());
+static REGISTRY: RwLock<Option<&'static GlobalAutoPluginRegistry>> = RwLock::new(None);
+
+/// The linker section entries and every registered [`BuildScriptEntries`], once any is
+pub(super) fn registry() -> Option<&'static GlobalAutoPluginRegistry> {
+    *REGISTRY.read().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Warns about entries registered since the last check that are bound to a plugin already built in the app
+pub(super) fn warn_late_entries(built: &mut BuiltAutoPlugins) {
+    let new_sources = {
+        let sources = SOURCES.lock().unwrap_or_else(|err| err.into_inner());
+        let new_sources = sources
+            .get(built.checked_sources..)
+            .map(<[_]>::to_vec)
+            .unwrap_or_default();
+        built.checked_sources = sources.len();
+        new_sources
+    };
+    for source in new_sources {
+        let mut late = Vec::new();
+        for factory in source.factories() {
+            factory.for_each_instance(&mut |type_id, type_name, _| {
+                if built.contains(type_id) && !late.contains(&type_name) {
+                    late.push(type_name);
+                }
+            });
+        }
+        for plugin in late {
+            bevy_log::warn!(
+                "`{plugin}` was built before the generated entries of `{}` were registered, so they don't run for it. Add `{}::AUTO_PLUGIN_ENTRIES` before it",
+                source.crate_name,
+                source.crate_name,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[internal_test_proc_macro::xtest]
+    fn test_entry_id_ignores_crate_name() {
+        assert_eq!(
+            entry_id("my_crate::systems::_global_auto_add_system_1a2b"),
+            entry_id("crate::systems::_global_auto_add_system_1a2b"),
+        );
+        assert_eq!(entry_id("my_crate::_entry"), entry_id("crate::_entry"));
+        assert_ne!(entry_id("crate::a::_entry"), entry_id("crate::b::_entry"));
+    }
+}
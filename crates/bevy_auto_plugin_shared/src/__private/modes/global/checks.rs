@@ -0,0 +1,153 @@
+//! Traits attribute targets are registered through, so a missing impl reports which attribute needs it
+//! instead of failing inside the registry entry.
+//!
+//! Each trait has what the registration needs as supertraits and the fn next to it does the registration,
+//! so the generated `checks::init_resource::<Target>(app)` call is the only place that can fail to compile.
+
+use bevy_app::{App, SubApp};
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
+use bevy_ecs::event::Event;
+use bevy_ecs::message::Message;
+use bevy_ecs::prelude::Name;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{IntoObserverSystem, IntoSystem, SystemInput};
+use bevy_ecs::world::FromWorld;
+use bevy_reflect::{FromReflect, GetTypeRegistration, TypePath, Typed};
+use bevy_state::app::AppExtStates;
+use bevy_state::prelude::{NextState, State};
+use bevy_state::state::FreelyMutableState;
+
+/// The main app or a sub app the registration goes to
+pub trait RegisterApp {
+    fn _sub_app(&mut self) -> &mut SubApp;
+}
+
+impl RegisterApp for App {
+    fn _sub_app(&mut self) -> &mut SubApp {
+        self.main_mut()
+    }
+}
+
+impl RegisterApp for SubApp {
+    fn _sub_app(&mut self) -> &mut SubApp {
+        self
+    }
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `Reflect` to use `auto_register_type`",
+    label = "required by `auto_register_type`"
+)]
+pub trait RegisterTypeTarget: GetTypeRegistration {}
+impl<T: GetTypeRegistration> RegisterTypeTarget for T {}
+
+pub fn register_type<T: RegisterTypeTarget>(app: &mut impl RegisterApp) {
+    app._sub_app().register_type::<T>();
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `Message` to use `auto_add_event`",
+    label = "required by `auto_add_event`"
+)]
+pub trait AddEventTarget: Message {}
+impl<T: Message> AddEventTarget for T {}
+
+pub fn add_event<T: AddEventTarget>(app: &mut impl RegisterApp) {
+    app._sub_app().add_message::<T>();
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `Resource` and `Default` (or `FromWorld`) to use `auto_init_resource`",
+    label = "required by `auto_init_resource`"
+)]
+pub trait InitResourceTarget: Resource + FromWorld {}
+impl<T: Resource + FromWorld> InitResourceTarget for T {}
+
+pub fn init_resource<T: InitResourceTarget>(app: &mut impl RegisterApp) {
+    app._sub_app().init_resource::<T>();
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `Resource` to use `auto_insert_resource`",
+    label = "required by `auto_insert_resource`"
+)]
+pub trait InsertResourceTarget: Resource {}
+impl<T: Resource> InsertResourceTarget for T {}
+
+pub fn insert_resource<T: InsertResourceTarget>(app: &mut impl RegisterApp, resource: T) {
+    app._sub_app().insert_resource(resource);
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `States` and `Default` (or `FromWorld`) to use `auto_init_state`",
+    label = "required by `auto_init_state`"
+)]
+pub trait InitStateTarget: FreelyMutableState + FromWorld {}
+impl<T: FreelyMutableState + FromWorld> InitStateTarget for T {}
+
+pub fn init_state<T: InitStateTarget>(app: &mut impl RegisterApp) {
+    app._sub_app().init_state::<T>();
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `Component` to use `auto_name`",
+    label = "required by `auto_name`"
+)]
+pub trait AutoNameTarget: Component {}
+impl<T: Component> AutoNameTarget for T {}
+
+pub fn auto_name<T: AutoNameTarget>(app: &mut impl RegisterApp, name: fn() -> Name) {
+    app._sub_app()
+        .world_mut()
+        .register_required_components_with::<T, Name>(name);
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `States` and `Reflect` to use `auto_register_state_type`",
+    label = "required by `auto_register_state_type`"
+)]
+pub trait RegisterStateTypeTarget:
+    FreelyMutableState + FromReflect + TypePath + Typed + GetTypeRegistration
+{
+}
+impl<T: FreelyMutableState + FromReflect + TypePath + Typed + GetTypeRegistration>
+    RegisterStateTypeTarget for T
+{
+}
+
+pub fn register_state_type<T: RegisterStateTypeTarget>(app: &mut impl RegisterApp) {
+    app._sub_app()
+        .register_type::<State<T>>()
+        .register_type::<NextState<T>>();
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must be a system to use `auto_add_system`",
+    label = "required by `auto_add_system`",
+    note = "a system is a fn whose params all implement `SystemParam`"
+)]
+pub trait AddSystemTarget<In: SystemInput, Out, M>: IntoSystem<In, Out, M> {}
+impl<In: SystemInput, Out, M, T: IntoSystem<In, Out, M>> AddSystemTarget<In, Out, M> for T {}
+
+/// Passes `system` on to the `add_systems` call, which then only fails to compile here
+pub fn add_system_target<In: SystemInput, Out, M>(
+    system: impl AddSystemTarget<In, Out, M>,
+) -> impl IntoSystem<In, Out, M> {
+    system
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must be an observer to use `auto_add_observer`",
+    label = "required by `auto_add_observer`",
+    note = "an observer is a fn taking `On<E>` first and `SystemParam`s after it"
+)]
+pub trait AddObserverTarget<E: Event, B: Bundle, M>: IntoObserverSystem<E, B, M> {}
+impl<E: Event, B: Bundle, M, T: IntoObserverSystem<E, B, M>> AddObserverTarget<E, B, M> for T {}
+
+/// Passes `observer` on to the `add_observer` call, which then only fails to compile here
+pub fn add_observer_target<E: Event, B: Bundle, M>(
+    observer: impl AddObserverTarget<E, B, M>,
+) -> impl IntoObserverSystem<E, B, M> {
+    observer
+}
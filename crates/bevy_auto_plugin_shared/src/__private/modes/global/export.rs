@@ -0,0 +1,417 @@
+use super::{BuiltAutoPlugins, EntrySource, GlobalAutoPluginRegistryEntryFactory, global_registry};
+use bevy_app::App;
+use bevy_ecs::world::World;
+use std::fmt::Write;
+
+/// A snapshot of the global registry for generating docs, see [`RegistryExport::to_dot`]
+/// and (with the `export` feature) [`RegistryExport::to_json`]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub struct RegistryExport {
+    /// Sorted by plugin name
+    pub plugins: Vec<PluginExport>,
+    /// Only known after building an app, see [`RegistryExport::with_app`]
+    pub sub_plugins: Vec<SubPluginExport>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub struct PluginExport {
+    pub name: &'static str,
+    /// Sorted by source location
+    pub entries: Vec<EntryExport>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub struct EntryExport {
+    pub kind: &'static str,
+    pub phase: &'static str,
+    pub target: Option<&'static str>,
+    pub group: Option<&'static str>,
+    pub ordering: Vec<OrderingExport>,
+    pub source: Option<SourceExport>,
+}
+
+/// A declared schedule or ordering constraint, e.g. `after = foo`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub struct OrderingExport {
+    pub arg: &'static str,
+    pub path: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub struct SourceExport {
+    pub module: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A plugin added by another plugin's build entries
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub struct SubPluginExport {
+    pub parent: &'static str,
+    pub child: &'static str,
+}
+
+impl From<EntrySource> for SourceExport {
+    fn from(source: EntrySource) -> Self {
+        Self {
+            module: source.module,
+            file: source.file,
+            line: source.line,
+            column: source.column,
+        }
+    }
+}
+
+impl PluginExport {
+    fn new(
+        name: &'static str,
+        factories: impl Iterator<Item = &'static GlobalAutoPluginRegistryEntryFactory>,
+    ) -> Self {
+        let mut entries = factories
+            .map(|factory| EntryExport {
+                kind: factory.kind().as_str(),
+                phase: factory.phase().as_str(),
+                target: factory.target(),
+                group: factory.group(),
+                ordering: factory
+                    .ordering()
+                    .iter()
+                    .map(|&(arg, path)| OrderingExport { arg, path })
+                    .collect(),
+                source: factory.source().map(SourceExport::from),
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|entry| {
+            entry
+                .source
+                .as_ref()
+                .map(|source| (source.file, source.line, source.column))
+        });
+        Self { name, entries }
+    }
+
+    /// Whether `name` is the full type name or the last path segment of this plugin
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.name.rsplit("::").next() == Some(name)
+    }
+}
+
+impl RegistryExport {
+    /// Every plugin and entry linked into the binary
+    pub fn from_registry() -> Self {
+        let mut plugins = global_registry()
+            .iter()
+            .map(|(_, name, factories)| PluginExport::new(name, factories))
+            .collect::<Vec<_>>();
+        plugins.sort_by_key(|plugin| plugin.name);
+        Self {
+            plugins,
+            sub_plugins: Vec::new(),
+        }
+    }
+
+    /// The plugins built in `world`, in build order, with their sub-plugin edges
+    pub fn from_world(world: &World) -> Self {
+        let Some(built) = world.get_resource::<BuiltAutoPlugins>() else {
+            return Self::default();
+        };
+        let plugins = built
+            .plugins()
+            .iter()
+            .map(|&(type_id, name)| {
+                let factories = global_registry()
+                    .iter()
+                    .filter(|(id, _, _)| *id == type_id)
+                    .flat_map(|(_, _, factories)| factories);
+                PluginExport::new(name, factories)
+            })
+            .collect();
+        Self {
+            plugins,
+            sub_plugins: Vec::new(),
+        }
+        .with_world(world)
+    }
+
+    /// Adds the plugin → sub-plugin edges observed while building `app`
+    pub fn with_app(self, app: &App) -> Self {
+        self.with_world(app.world())
+    }
+
+    fn with_world(mut self, world: &World) -> Self {
+        if let Some(built) = world.get_resource::<BuiltAutoPlugins>() {
+            self.sub_plugins.extend(
+                built
+                    .sub_plugins()
+                    .iter()
+                    .map(|&(parent, child)| SubPluginExport { parent, child }),
+            );
+        }
+        self
+    }
+
+    #[cfg(feature = "export")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("registry export is always serializable")
+    }
+
+    /// `[{ "name": .., "entries": <count> }]` for every plugin
+    #[cfg(feature = "export")]
+    pub fn list_value(&self) -> serde_json::Value {
+        self.plugins
+            .iter()
+            .map(|plugin| serde_json::json!({ "name": plugin.name, "entries": plugin.entries.len() }))
+            .collect()
+    }
+
+    /// The plugins with their entries, or only the one matching `plugin` (see [`PluginExport::is_named`])
+    #[cfg(feature = "export")]
+    pub fn entries_value(&self, plugin: Option<&str>) -> Result<serde_json::Value, String> {
+        let plugins = self
+            .plugins
+            .iter()
+            .filter(|export| plugin.is_none_or(|name| export.is_named(name)))
+            .collect::<Vec<_>>();
+        if let Some(name) = plugin
+            && plugins.is_empty()
+        {
+            return Err(format!("no auto plugin named `{name}` was built"));
+        }
+        serde_json::to_value(plugins).map_err(|err| err.to_string())
+    }
+
+    /// Graphviz graph of plugin → entry and plugin → sub-plugin edges.
+    ///
+    /// Systems are keyed by module path and name so `before`/`after` constraints between them become dashed edges,
+    /// with the constraint's path resolved from the module declaring the system. `in_set` points at a node for the set.
+    pub fn to_dot(&self) -> String {
+        fn quoted(value: &str) -> String {
+            let escaped = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("\"{escaped}\"")
+        }
+
+        // crate names of the declaring modules, paths starting with one of them are absolute
+        let crates = self
+            .plugins
+            .iter()
+            .flat_map(|plugin| &plugin.entries)
+            .filter_map(|entry| entry.source.as_ref())
+            .filter_map(|source| source.module.split("::").next())
+            .collect::<Vec<_>>();
+        let system_id = |module: Option<&str>, path: &str| {
+            quoted(&format!("system:{}", resolve_path(module, path, &crates)))
+        };
+
+        let mut dot = String::from("digraph auto_plugin {\n    rankdir=LR;\n");
+        for plugin in &self.plugins {
+            let plugin_id = quoted(&format!("plugin:{}", plugin.name));
+            let _ = writeln!(
+                dot,
+                "    {plugin_id} [shape=box, label={}];",
+                quoted(plugin.name)
+            );
+            for (i, entry) in plugin.entries.iter().enumerate() {
+                let target = entry.target.unwrap_or_default();
+                let module = entry.source.as_ref().map(|source| source.module);
+                let entry_id = match entry.kind {
+                    "add_system" => system_id(module, target),
+                    _ => quoted(&format!("entry:{}:{i}", plugin.name)),
+                };
+                let mut label = format!("{}\n{target}", entry.kind);
+                for ordering in &entry.ordering {
+                    if ordering.arg == "schedule" {
+                        let _ = write!(label, "\n({})", ordering.path);
+                    }
+                }
+                let _ = writeln!(dot, "    {entry_id} [label={}];", quoted(&label));
+                let _ = writeln!(dot, "    {plugin_id} -> {entry_id};");
+                for ordering in &entry.ordering {
+                    let other = system_id(module, ordering.path);
+                    let edge = match ordering.arg {
+                        "before" | "before_ignore_deferred" => format!("{entry_id} -> {other}"),
+                        "after" | "after_ignore_deferred" => format!("{other} -> {entry_id}"),
+                        "in_set" => {
+                            let set = quoted(&format!("set:{}", ordering.path));
+                            let _ = writeln!(
+                                dot,
+                                "    {set} [shape=hexagon, label={}];",
+                                quoted(ordering.path)
+                            );
+                            format!("{entry_id} -> {set}")
+                        }
+                        _ => continue,
+                    };
+                    let _ = writeln!(
+                        dot,
+                        "    {edge} [style=dashed, label={}];",
+                        quoted(ordering.arg)
+                    );
+                }
+            }
+        }
+        for SubPluginExport { parent, child } in &self.sub_plugins {
+            let _ = writeln!(
+                dot,
+                "    {} -> {} [style=bold, label=\"add_plugins\"];",
+                quoted(&format!("plugin:{parent}")),
+                quoted(&format!("plugin:{child}")),
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// `path` as written in `module` made absolute: `crate`, `self` and `super` are resolved against `module`,
+/// paths starting with one of `crates` are kept, and anything else is taken as relative to `module`
+fn resolve_path(module: Option<&str>, path: &str, crates: &[&str]) -> String {
+    let Some(module) = module else {
+        return path.to_string();
+    };
+    if let Some(path) = path.strip_prefix("::") {
+        return path.to_string();
+    }
+    let mut resolved = module.split("::").collect::<Vec<_>>();
+    let mut segments = path.split("::").peekable();
+    match segments.peek().copied() {
+        Some("crate") => {
+            resolved.truncate(1);
+            segments.next();
+        }
+        Some("self") => {
+            segments.next();
+        }
+        Some("super") => {
+            while segments.next_if_eq(&"super").is_some() {
+                resolved.pop();
+            }
+        }
+        Some(first) if path.contains("::") && crates.contains(&first) => resolved.clear(),
+        _ => {}
+    }
+    resolved.extend(segments);
+    resolved.join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export() -> RegistryExport {
+        RegistryExport {
+            plugins: vec![PluginExport {
+                name: "MyPlugin",
+                entries: vec![EntryExport {
+                    kind: "add_system",
+                    phase: "build",
+                    target: Some("foo"),
+                    group: None,
+                    ordering: vec![
+                        OrderingExport {
+                            arg: "schedule",
+                            path: "Update",
+                        },
+                        OrderingExport {
+                            arg: "after",
+                            path: "bar",
+                        },
+                    ],
+                    source: None,
+                }],
+            }],
+            sub_plugins: vec![SubPluginExport {
+                parent: "MyPlugin",
+                child: "Child",
+            }],
+        }
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_to_dot() {
+        let dot = export().to_dot();
+        for line in [
+            r#""plugin:MyPlugin" [shape=box, label="MyPlugin"];"#,
+            r#""system:foo" [label="add_system\nfoo\n(Update)"];"#,
+            r#""plugin:MyPlugin" -> "system:foo";"#,
+            r#""system:bar" -> "system:foo" [style=dashed, label="after"];"#,
+            r#""plugin:MyPlugin" -> "plugin:Child" [style=bold, label="add_plugins"];"#,
+        ] {
+            assert!(dot.contains(line), "missing `{line}` in:\n{dot}");
+        }
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_to_dot_same_named_systems() {
+        let system = |module, ordering| EntryExport {
+            kind: "add_system",
+            phase: "build",
+            target: Some("update"),
+            group: None,
+            ordering,
+            source: Some(SourceExport {
+                module,
+                file: "src/lib.rs",
+                line: 1,
+                column: 1,
+            }),
+        };
+        let export = RegistryExport {
+            plugins: vec![PluginExport {
+                name: "MyPlugin",
+                entries: vec![
+                    system("my_crate::a", vec![]),
+                    system(
+                        "my_crate::b",
+                        vec![
+                            OrderingExport {
+                                arg: "after",
+                                path: "super::a::update",
+                            },
+                            OrderingExport {
+                                arg: "before",
+                                path: "crate::c::update",
+                            },
+                            OrderingExport {
+                                arg: "before",
+                                path: "render",
+                            },
+                        ],
+                    ),
+                ],
+            }],
+            sub_plugins: Vec::new(),
+        };
+        let dot = export.to_dot();
+        for line in [
+            r#""plugin:MyPlugin" -> "system:my_crate::a::update";"#,
+            r#""plugin:MyPlugin" -> "system:my_crate::b::update";"#,
+            r#""system:my_crate::a::update" -> "system:my_crate::b::update" [style=dashed, label="after"];"#,
+            r#""system:my_crate::b::update" -> "system:my_crate::c::update" [style=dashed, label="before"];"#,
+            r#""system:my_crate::b::update" -> "system:my_crate::b::render" [style=dashed, label="before"];"#,
+        ] {
+            assert!(dot.contains(line), "missing `{line}` in:\n{dot}");
+        }
+        assert!(!dot.contains(r#""system:update""#), "{dot}");
+    }
+
+    #[cfg(feature = "export")]
+    #[internal_test_proc_macro::xtest]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&export().to_json()).unwrap();
+        assert_eq!(
+            json["plugins"][0]["entries"][0]["ordering"][1]["arg"],
+            "after"
+        );
+        assert_eq!(json["sub_plugins"][0]["child"], "Child");
+    }
+}
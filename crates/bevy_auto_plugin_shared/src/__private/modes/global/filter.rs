@@ -0,0 +1,168 @@
+use crate::__private::attribute::AutoPluginItemAttribute;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{ToTokens, quote};
+
+/// What a registry entry registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AutoPluginEntryKind {
+    RegisterType,
+    AddEvent,
+    InitResource,
+    InsertResource,
+    InitState,
+    AutoName,
+    RegisterStateType,
+    AddSystem,
+    AddObserver,
+    /// `auto_plugin_entry!` closures
+    Custom,
+}
+
+impl AutoPluginEntryKind {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::RegisterType => "register_type",
+            Self::AddEvent => "add_event",
+            Self::InitResource => "init_resource",
+            Self::InsertResource => "insert_resource",
+            Self::InitState => "init_state",
+            Self::AutoName => "auto_name",
+            Self::RegisterStateType => "register_state_type",
+            Self::AddSystem => "add_system",
+            Self::AddObserver => "add_observer",
+            Self::Custom => "custom",
+        }
+    }
+}
+
+impl From<AutoPluginItemAttribute> for AutoPluginEntryKind {
+    fn from(value: AutoPluginItemAttribute) -> Self {
+        match value {
+            AutoPluginItemAttribute::RegisterType => Self::RegisterType,
+            AutoPluginItemAttribute::AddEvent => Self::AddEvent,
+            AutoPluginItemAttribute::InitResource => Self::InitResource,
+            AutoPluginItemAttribute::InsertResource => Self::InsertResource,
+            AutoPluginItemAttribute::InitState => Self::InitState,
+            AutoPluginItemAttribute::AutoName => Self::AutoName,
+            AutoPluginItemAttribute::RegisterStateType => Self::RegisterStateType,
+            AutoPluginItemAttribute::AddSystem => Self::AddSystem,
+            AutoPluginItemAttribute::AddObserver => Self::AddObserver,
+        }
+    }
+}
+
+impl ToTokens for AutoPluginEntryKind {
+    fn to_tokens(&self, tokens: &mut MacroStream) {
+        let variant = quote::format_ident!("{}", format!("{self:?}"));
+        tokens.extend(quote! {
+            ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPluginEntryKind::#variant
+        });
+    }
+}
+
+/// Selects which registry entries [`super::AutoPlugin::build_filtered`] runs.
+///
+/// The default filter matches every entry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AutoPluginFilter {
+    kinds: Option<Vec<AutoPluginEntryKind>>,
+    excluded_kinds: Vec<AutoPluginEntryKind>,
+    groups: Option<Vec<String>>,
+    excluded_groups: Vec<String>,
+}
+
+impl AutoPluginFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Only run entries of these kinds
+    pub fn with_kinds(mut self, kinds: impl IntoIterator<Item = AutoPluginEntryKind>) -> Self {
+        self.kinds.get_or_insert_default().extend(kinds);
+        self
+    }
+    /// Skip entries of these kinds
+    pub fn without_kinds(mut self, kinds: impl IntoIterator<Item = AutoPluginEntryKind>) -> Self {
+        self.excluded_kinds.extend(kinds);
+        self
+    }
+    /// Only run entries tagged with one of these `group`s, untagged entries are skipped
+    pub fn with_groups(mut self, groups: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.groups
+            .get_or_insert_default()
+            .extend(groups.into_iter().map(Into::into));
+        self
+    }
+    /// Skip entries tagged with any of these `group`s
+    pub fn without_groups(mut self, groups: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.excluded_groups
+            .extend(groups.into_iter().map(Into::into));
+        self
+    }
+    pub fn matches(&self, kind: AutoPluginEntryKind, group: Option<&str>) -> bool {
+        if self
+            .kinds
+            .as_ref()
+            .is_some_and(|kinds| !kinds.contains(&kind))
+        {
+            return false;
+        }
+        if self.excluded_kinds.contains(&kind) {
+            return false;
+        }
+        if let Some(groups) = &self.groups
+            && !group.is_some_and(|group| groups.iter().any(|g| g == group))
+        {
+            return false;
+        }
+        if group.is_some_and(|group| self.excluded_groups.iter().any(|g| g == group)) {
+            return false;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[internal_test_proc_macro::xtest]
+    fn test_default_matches_all() {
+        let filter = AutoPluginFilter::new();
+        assert!(filter.matches(AutoPluginEntryKind::AddSystem, None));
+        assert!(filter.matches(AutoPluginEntryKind::Custom, Some("debug")));
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_kinds() {
+        let filter = AutoPluginFilter::new()
+            .with_kinds([
+                AutoPluginEntryKind::RegisterType,
+                AutoPluginEntryKind::AddObserver,
+            ])
+            .without_kinds([AutoPluginEntryKind::AddObserver]);
+        assert!(filter.matches(AutoPluginEntryKind::RegisterType, None));
+        assert!(!filter.matches(AutoPluginEntryKind::AddObserver, None));
+        assert!(!filter.matches(AutoPluginEntryKind::AddSystem, None));
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_groups() {
+        let filter = AutoPluginFilter::new().with_groups(["debug"]);
+        assert!(filter.matches(AutoPluginEntryKind::AddSystem, Some("debug")));
+        assert!(!filter.matches(AutoPluginEntryKind::AddSystem, Some("net")));
+        assert!(!filter.matches(AutoPluginEntryKind::AddSystem, None));
+
+        let filter = AutoPluginFilter::new().without_groups(["debug"]);
+        assert!(!filter.matches(AutoPluginEntryKind::AddSystem, Some("debug")));
+        assert!(filter.matches(AutoPluginEntryKind::AddSystem, Some("net")));
+        assert!(filter.matches(AutoPluginEntryKind::AddSystem, None));
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_kind_to_tokens() {
+        assert_eq!(
+            AutoPluginEntryKind::AddSystem.to_token_stream().to_string(),
+            quote!(::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPluginEntryKind::AddSystem).to_string()
+        );
+    }
+}
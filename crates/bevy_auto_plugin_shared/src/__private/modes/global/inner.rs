@@ -1,3 +1,5 @@
+use crate::__private::attribute::AutoPluginAttribute;
+use crate::__private::attribute_args::AutoPluginAttributeKind;
 use crate::__private::attribute_args::attributes::add_event::AddEventAttributeArgs;
 use crate::__private::attribute_args::attributes::add_observer::AddObserverAttributeArgs;
 use crate::__private::attribute_args::attributes::add_system::AddSystemAttributeArgs;
@@ -5,7 +7,16 @@ use crate::__private::attribute_args::attributes::auto_name::AutoNameAttributeAr
 use crate::__private::attribute_args::attributes::init_resource::InitResourceAttributeArgs;
 use crate::__private::attribute_args::attributes::init_state::InitStateAttributeArgs;
 use crate::__private::attribute_args::attributes::insert_resource::InsertResourceAttributeArgs;
-use crate::__private::attribute_args::attributes::modes::global::auto_plugin::AutoPluginFnAttributeArgs;
+use crate::__private::attribute_args::attributes::modes::global::auto_generics_set::{
+    AutoGenericsSetArgs, ExpandedGenericsArgs, expand_generics_args,
+};
+use crate::__private::attribute_args::attributes::modes::global::auto_plugin::{
+    AutoPluginFnAttributeArgs, AutoPluginStructOrEnumAttributeArgs,
+};
+use crate::__private::attribute_args::attributes::modes::global::auto_plugin_entry::AutoPluginEntryArgs;
+use crate::__private::attribute_args::attributes::modes::global::auto_preset::{
+    AutoApplyPresetArgs, AutoPresetArgs, AutoPresetExpandArgs,
+};
 use crate::__private::attribute_args::attributes::modes::resolve_app_param_name;
 use crate::__private::attribute_args::attributes::register_state_type::RegisterStateTypeAttributeArgs;
 use crate::__private::attribute_args::attributes::register_type::RegisterTypeAttributeArgs;
@@ -18,15 +29,24 @@ use crate::__private::attribute_args::attributes::shorthand::states::StatesAttri
 use crate::__private::attribute_args::attributes::shorthand::system::SystemAttributeArgs;
 use crate::__private::attribute_args::derives::auto_plugin::GlobalAutoPluginDeriveArgs;
 use crate::__private::attribute_args::{
-    GlobalArgs, GlobalAttributeArgs, ItemAttributeArgs, WithTargetPath,
+    AttributeKeys, GLOBAL_KEYS, GlobalArgs, GlobalAttributeArgs, ItemAttributeArgs, WithTargetPath,
+    parse_args_with_valid_keys, with_valid_keys,
 };
-use crate::__private::modes::global::_plugin_entry_block;
+use crate::__private::modes::global::{
+    _generic_instances_ident, _generic_plugin_entry_block, _plugin_entry_block,
+    _sub_app_register_block, AutoPluginEntryKind, PluginEntryMeta, build_script,
+};
+use crate::__private::util::concrete_path::validate_generic_counts;
 use crate::__private::util::debug::debug_item;
+use crate::__private::util::extensions::item::ItemGenericsExt;
 use crate::__private::util::fn_param::require_fn_param_mutable_reference;
-use crate::{ok_or_return_compiler_error, parse_macro_input2};
+use crate::__private::util::tokens::{combine_errors, prefix_errors};
+use crate::parse_macro_input2;
 use darling::FromMeta;
-use proc_macro2::{Ident, Span, TokenStream as MacroStream};
+use proc_macro2::{Ident, TokenStream as MacroStream};
 use quote::quote;
+use syn::parse::Parser;
+use syn::spanned::Spanned;
 use syn::{FnArg, Item, ItemFn, parse2};
 
 fn global_attribute_inner<A, F>(
@@ -43,16 +63,42 @@ where
     let attr = attr.into();
     let input = input.into();
 
-    let item: Item = ok_or_return_compiler_error!(parse2(input));
+    let item: Item = match parse2(input.clone()) {
+        Ok(item) => item,
+        Err(err) => {
+            let err = prefix_errors("failed to parse", err).to_compile_error();
+            return quote!( #input #err );
+        }
+    };
 
+    // report every error at once, and always keep the item so uses of it elsewhere still resolve
     let err_msg = format!("Attribute macro is not allowed on {}", debug_item(&item));
-    let ident = ok_or_return_compiler_error!(resolve_ident(&item), err_msg);
+    let ident = resolve_ident(&item).map_err(|err| prefix_errors(err_msg, err));
+    let args = parse_attr(attr).map_err(|err| prefix_errors("failed to parse", err));
 
-    let args = ok_or_return_compiler_error!(parse_attr(attr));
+    let output = match (ident, args) {
+        (Ok(ident), Ok(args)) => {
+            body(ident, args, &item).map_err(|err| prefix_errors("failed to parse", err))
+        }
+        (ident, args) => Err(combine_errors(ident.err().into_iter().chain(args.err()))
+            .expect("at least one of ident or args failed")),
+    };
 
-    let output = ok_or_return_compiler_error!(body(ident, args, &item));
+    match output {
+        Ok(output) => quote!( #item #output ),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote!( #item #err )
+        }
+    }
+}
 
-    quote!( #item #output )
+/// Span of the item itself, past the attributes left on it
+fn item_span(item: &Item) -> proc_macro2::Span {
+    use crate::__private::util::extensions::item::ItemAttrsExt;
+    let mut item = item.clone();
+    let _ = item.take_attrs();
+    item.span()
 }
 
 pub fn global_attribute_outer<T>(
@@ -64,25 +110,75 @@ where
 {
     /// Maps [`crate::__private::util::resolve_ident_from_item::IdentFromItemResult`] to [`syn::Result<&Ident>`]
     fn resolve_item_ident<T: GlobalAttributeArgs>(item: &Item) -> syn::Result<&Ident> {
-        T::Inner::resolve_item_ident(item).map_err(|err| syn::Error::new(Span::call_site(), err))
+        T::Inner::resolve_item_ident(item).map_err(|err| syn::Error::new(item_span(item), err))
     }
 
+    fn parse_attr<T: GlobalAttributeArgs>(attr: MacroStream) -> syn::Result<T> {
+        let attribute = T::Inner::attribute().ident_str();
+        parse_args_with_valid_keys::<T, T::Inner>(attr, attribute, "global", GLOBAL_KEYS)
+    }
+
+    let input = input.into();
+    let attr = match expand_generics_args(T::Inner::attribute().ident_str(), attr.into(), &input) {
+        Ok(ExpandedGenericsArgs::Args(attr)) => attr,
+        Ok(ExpandedGenericsArgs::Deferred(output)) => return output,
+        Err(err) => {
+            let err = err.to_compile_error();
+            return quote!( #input #err );
+        }
+    };
+
     global_attribute_inner(
         attr,
         input,
         resolve_item_ident::<T>,
-        parse2::<T>,
-        |ident, params, _item| {
+        parse_attr::<T>,
+        |ident, params, item| {
             let unique_ident = params.get_unique_ident(ident);
+            let target = ident.to_string();
             let plugin = params.plugin().clone();
+            let group = params.group().map(str::to_string);
+            let meta = PluginEntryMeta {
+                phase: params.phase(),
+                kind: T::Inner::attribute().into(),
+                group: group.as_deref(),
+                target: Some(&target),
+                ordering: params.inner().ordering(),
+            };
+            let sub_app = params.sub_app().cloned();
+            if let Some(generics) = item.generics() {
+                validate_generic_counts(generics, &params)?;
+            }
+            let generic_entry_generics = resolve_generic_entry_generics(item, &params)?;
             let with_target_path = WithTargetPath::from((ident.into(), params));
+            let app = match &sub_app {
+                Some(_) => quote! { sub_app },
+                None => quote! { app },
+            };
             let output = with_target_path
-                .to_tokens_iter()
-                .map(|input| {
-                    let register = quote! { app #input ; };
-                    let expr: syn::ExprClosure = syn::parse_quote!(|app| { #register });
-                    let output = _plugin_entry_block(&unique_ident, &plugin, &expr);
-                    Ok(output)
+                .register_statements_iter(&app)
+                .enumerate()
+                .map(|(ix, register)| {
+                    // one static per `generics(..)` list
+                    let unique_ident = match ix {
+                        0 => unique_ident.clone(),
+                        ix => quote::format_ident!("{unique_ident}_{ix}"),
+                    };
+                    let register = match &sub_app {
+                        Some(label) => _sub_app_register_block(label, &plugin, &register),
+                        None => register,
+                    };
+                    let expr: syn::ExprClosure = syn::parse_quote!(|app, _plugin| { #register });
+                    match &generic_entry_generics {
+                        Some(generics) => _generic_plugin_entry_block(
+                            &unique_ident,
+                            &plugin,
+                            generics,
+                            &expr,
+                            &meta,
+                        ),
+                        None => Ok(_plugin_entry_block(&unique_ident, &plugin, &expr, &meta)),
+                    }
                 })
                 .collect::<syn::Result<MacroStream>>()?;
             assert!(
@@ -94,14 +190,93 @@ where
     )
 }
 
+/// Returns the item's generics when the entry uses them as-is (`plugin = MyPlugin<T>, generics(T)`),
+/// in which case the entry is instantiated for each concrete plugin instead of a single one
+fn resolve_generic_entry_generics<T: GlobalAttributeArgs>(
+    item: &Item,
+    params: &T,
+) -> syn::Result<Option<syn::Generics>> {
+    use crate::__private::util::extensions::path::PathExt;
+    let Some(generics) = item.generics() else {
+        return Ok(None);
+    };
+    // type and const params, lifetimes are left to inference
+    let type_params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => Some(&param.ident),
+            syn::GenericParam::Const(param) => Some(&param.ident),
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let is_param = |arg: &syn::GenericArgument, param: Option<&Ident>| match arg {
+        syn::GenericArgument::Type(syn::Type::Path(ty)) if ty.qself.is_none() => {
+            ty.path.get_ident().is_some_and(|ident| match param {
+                Some(param) => ident == param,
+                None => type_params.contains(&ident),
+            })
+        }
+        _ => false,
+    };
+    let type_lists = params.type_lists();
+    if !type_lists
+        .iter()
+        .flat_map(|tl| tl.0.iter())
+        .any(|arg| is_param(arg, None))
+    {
+        return Ok(None);
+    }
+    let [type_list] = type_lists else {
+        // the lists past the first one
+        let extra = type_lists.get(1..).unwrap_or_default();
+        return Err(syn::Error::new_spanned(
+            quote! { #(#extra)* },
+            "generic entries accept a single `generics(..)` list of the item's type parameters",
+        ));
+    };
+    let in_order = type_list.non_lifetimes().count() == type_params.len()
+        && type_list
+            .non_lifetimes()
+            .zip(&type_params)
+            .all(|(arg, param)| is_param(arg, Some(param)));
+    if !in_order {
+        return Err(syn::Error::new_spanned(
+            type_list,
+            "generic entries must list all of the item's type parameters in order",
+        ));
+    }
+    if !params.plugin().has_generics()? {
+        return Err(syn::Error::new_spanned(
+            params.plugin(),
+            "generic entries require a generic plugin, e.g. `plugin = MyPlugin::<T>`",
+        ));
+    }
+    Ok(Some(generics.clone()))
+}
+
 pub fn expand_global_auto_plugin(attr: MacroStream, input: MacroStream) -> MacroStream {
+    // keep the item on errors so uses of it elsewhere still resolve
+    expand_global_auto_plugin_inner(attr, input.clone()).unwrap_or_else(|err| {
+        let err = err.to_compile_error();
+        quote! { #input #err }
+    })
+}
+
+fn expand_global_auto_plugin_inner(
+    attr: MacroStream,
+    input: MacroStream,
+) -> syn::Result<MacroStream> {
     use quote::quote;
-    use syn::spanned::Spanned;
-    let item = parse_macro_input2!(input as ItemFn);
-    let params = match parse2::<AutoPluginFnAttributeArgs>(attr) {
-        Ok(params) => params,
-        Err(err) => return err.into_compile_error(),
-    };
+    let item = parse2::<ItemFn>(input)?;
+    let params =
+        parse_args_with_valid_keys::<AutoPluginFnAttributeArgs, AutoPluginFnAttributeArgs>(
+            attr,
+            "auto_plugin",
+            "global",
+            &[],
+        )
+        .map_err(|err| prefix_errors("failed to parse", err))?;
     let vis = &item.vis;
     let attrs = &item.attrs;
     let sig = &item.sig;
@@ -119,48 +294,67 @@ pub fn expand_global_auto_plugin(attr: MacroStream, input: MacroStream) -> Macro
     let self_arg = self_args.first();
 
     // TODO: use helper
-    let app_param_ident = match resolve_app_param_name(&item, params.app_param.as_ref()) {
-        Ok(ident) => ident,
-        Err(err) => return err.into_compile_error(),
-    };
-
-    if let Err(err) = require_fn_param_mutable_reference(&item, app_param_ident, "bevy app") {
-        return err.to_compile_error();
-    }
+    let app_param_ident = resolve_app_param_name(&item, params.app_param.as_ref())?;
+    require_fn_param_mutable_reference(&item, app_param_ident, "bevy app")?;
 
     let mut impl_plugin = quote! {};
 
     let auto_plugin_hook = if let Some(self_arg) = self_arg {
         if params.plugin.is_some() {
-            return syn::Error::new(
+            return Err(syn::Error::new(
                 params.plugin.span(),
                 "auto_plugin on trait impl can't specify plugin ident",
-            )
-            .to_compile_error();
+            ));
+        };
+        if params.is_unique.is_some() {
+            return Err(syn::Error::new(
+                params.is_unique.span(),
+                "auto_plugin on trait impl can't specify is_unique, override `Plugin::is_unique` instead",
+            ));
+        };
+        // `Plugin::build`, `Plugin::finish` and `Plugin::cleanup` run their respective phase
+        let phase_fn = match fn_ident.to_string().as_str() {
+            "build" => quote!(build),
+            "finish" => quote!(finish),
+            "cleanup" => quote!(cleanup),
+            _ => {
+                return Err(syn::Error::new(
+                    fn_ident.span(),
+                    "auto_plugin on trait impl only supports `build`, `finish` or `cleanup`",
+                ));
+            }
         };
         quote! {
-            <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::build(#self_arg, #app_param_ident);
+            <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::#phase_fn(#self_arg, #app_param_ident);
         }
     } else {
         if sig.inputs.len() > 1 {
-            return syn::Error::new(
+            return Err(syn::Error::new(
                 sig.inputs.span(),
                 "auto_plugin on bare fn can only accept a single parameter with the type &mut bevy::prelude::App",
-            )
-            .to_compile_error();
+            ));
         }
+        let is_unique_fn = is_unique_fn(params.is_unique);
         let Some(plugin_ident) = params.plugin else {
-            return syn::Error::new(
+            return Err(syn::Error::new(
                 params.plugin.span(),
                 "auto_plugin on bare fn requires the plugin ident to be specified",
-            )
-            .to_compile_error();
+            ));
         };
         impl_plugin.extend(quote! {
+            // entries reading the plugin instance (e.g. `from_plugin`) fail to compile against it
+            impl ::bevy_auto_plugin::__private::shared::__private::modes::global::_PluginBuiltWithoutInstance for #plugin_ident {}
             impl ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::Plugin for #plugin_ident {
                 fn build(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
                     #fn_ident(app);
                 }
+                fn finish(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
+                    <#plugin_ident as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::finish(self, app);
+                }
+                fn cleanup(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
+                    <#plugin_ident as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::cleanup(self, app);
+                }
+                #is_unique_fn
             }
         });
         quote! {
@@ -168,7 +362,7 @@ pub fn expand_global_auto_plugin(attr: MacroStream, input: MacroStream) -> Macro
         }
     };
 
-    quote! {
+    Ok(quote! {
         #(#attrs)*
         #vis #sig
         {
@@ -177,6 +371,105 @@ pub fn expand_global_auto_plugin(attr: MacroStream, input: MacroStream) -> Macro
         }
 
         #impl_plugin
+    })
+}
+
+/// `Plugin::is_unique` override for `#[auto_plugin(is_unique = ..)]`
+fn is_unique_fn(is_unique: Option<bool>) -> MacroStream {
+    match is_unique {
+        Some(is_unique) => quote! {
+            fn is_unique(&self) -> bool {
+                #is_unique
+            }
+        },
+        None => MacroStream::new(),
+    }
+}
+
+pub fn expand_global_auto_plugin_entry(input: MacroStream) -> MacroStream {
+    let args = parse_macro_input2!(input as AutoPluginEntryArgs);
+    let unique_ident = args.get_unique_ident();
+    _plugin_entry_block(
+        &unique_ident,
+        &args.plugin,
+        &args.entry_closure(),
+        &PluginEntryMeta {
+            phase: args.phase,
+            kind: AutoPluginEntryKind::Custom,
+            group: args.group.as_ref().map(|group| group.value()).as_deref(),
+            target: None,
+            ordering: Vec::new(),
+        },
+    )
+}
+
+/// Defines the preset as a `macro_rules!` taking the use site args and item, handing both
+/// back to `auto_preset_expand` along with the preset's own args
+pub fn expand_global_auto_preset(input: MacroStream) -> MacroStream {
+    let AutoPresetArgs {
+        attrs,
+        name,
+        attribute,
+        args,
+    } = parse_macro_input2!(input as AutoPresetArgs);
+    quote! {
+        #(#attrs)*
+        macro_rules! #name {
+            ([$($args:tt)*] $($item:tt)*) => {
+                #[::bevy_auto_plugin::__private::auto_preset_expand(#attribute(#(#args),*), [$($args)*])]
+                $($item)*
+            };
+        }
+    }
+}
+
+/// Defines the set as a `macro_rules!` taking an attribute and its item, adding the set's
+/// `generics(..)` lists to the attribute's args
+pub fn expand_global_auto_generics_set(input: MacroStream) -> MacroStream {
+    let args = parse_macro_input2!(input as AutoGenericsSetArgs);
+    let AutoGenericsSetArgs { attrs, name, .. } = &args;
+    let generics = args.generics_args();
+    quote! {
+        #(#attrs)*
+        macro_rules! #name {
+            (#[:: $($path:ident)::+ ($($args:tt)*)] $($item:tt)*) => {
+                #[:: $($path)::+ (#(#generics,)* $($args)*)]
+                $($item)*
+            };
+            (#[$($path:ident)::+ ($($args:tt)*)] $($item:tt)*) => {
+                #[$($path)::+ (#(#generics,)* $($args)*)]
+                $($item)*
+            };
+        }
+    }
+}
+
+pub fn global_auto_apply_preset_outer(attr: MacroStream, input: MacroStream) -> MacroStream {
+    match parse2::<AutoApplyPresetArgs>(attr) {
+        Ok(AutoApplyPresetArgs { preset, args }) => quote! {
+            #preset! { [#(#args),*] #input }
+        },
+        Err(err) => {
+            // keep the item on errors so uses of it elsewhere still resolve
+            let err = err.to_compile_error();
+            quote! { #input #err }
+        }
+    }
+}
+
+pub fn global_auto_preset_expand_outer(attr: MacroStream, input: MacroStream) -> MacroStream {
+    match parse2::<AutoPresetExpandArgs>(attr) {
+        Ok(args) => {
+            let attribute = args.to_attribute();
+            quote! {
+                #attribute
+                #input
+            }
+        }
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote! { #input #err }
+        }
     }
 }
 
@@ -189,7 +482,15 @@ pub fn expand_global_derive_global_auto_plugin(input: MacroStream) -> MacroStrea
     let derive_input = parse_macro_input2!(input as DeriveInput);
     let params = match GlobalAutoPluginDeriveArgs::from_derive_input(&derive_input) {
         Ok(params) => params,
-        Err(err) => return err.write_errors(),
+        Err(err) => {
+            return with_valid_keys::<AutoPluginStructOrEnumAttributeArgs>(
+                err,
+                "auto_plugin",
+                "global",
+                &[],
+            )
+            .into_compile_error();
+        }
     };
     let ident = &params.ident; // `Test`
     let generics = &params.generics; // `<T1, T2>`
@@ -197,6 +498,102 @@ pub fn expand_global_derive_global_auto_plugin(input: MacroStream) -> MacroStrea
 
     let mut output = MacroStream::new();
 
+    let auto_plugin_body = if params.auto_plugin.insert_self_as_resource {
+        quote! {
+            fn build(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
+                app.insert_resource(::core::clone::Clone::clone(self));
+                <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::run_phase(
+                    self,
+                    app,
+                    ::bevy_auto_plugin::__private::shared::__private::phase::PluginPhase::Build,
+                );
+            }
+        }
+    } else {
+        MacroStream::new()
+    };
+
+    let is_unique_fn = is_unique_fn(params.auto_plugin.is_unique);
+
+    if generics.type_params().next().is_some() {
+        let vis = &params.vis;
+        let instances_ident = _generic_instances_ident(ident);
+        let instances = params
+            .auto_plugin
+            .generics
+            .iter()
+            .map(|tl| quote!(#ident<#tl>))
+            .collect::<Vec<_>>();
+        output.extend(quote! {
+            /// Concrete instances of the plugin that generic entries are instantiated for
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #vis struct #instances_ident;
+
+            impl #instances_ident {
+                #[allow(unused_variables)]
+                pub fn instances<V>(
+                    add: &mut dyn FnMut(
+                        ::std::any::TypeId,
+                        &'static str,
+                        ::bevy_auto_plugin::__private::shared::__private::modes::global::BevyAppBuildFn,
+                    ),
+                )
+                where
+                    #instances_ident: ::bevy_auto_plugin::__private::shared::__private::modes::global::_HasListedGenerics<V>,
+                    #(V: ::bevy_auto_plugin::__private::shared::__private::modes::global::GenericAutoPluginEntry<#instances>,)*
+                {
+                    #(
+                        add(
+                            <#instances as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPluginTypeId>::type_id(),
+                            ::core::any::type_name::<#instances>(),
+                            <V as ::bevy_auto_plugin::__private::shared::__private::modes::global::GenericAutoPluginEntry<#instances>>::build_fn(),
+                        );
+                    )*
+                }
+            }
+        });
+        if !instances.is_empty() {
+            output.extend(quote! {
+                impl<V> ::bevy_auto_plugin::__private::shared::__private::modes::global::_HasListedGenerics<V>
+                    for #instances_ident
+                {
+                }
+
+                #(
+                    impl ::bevy_auto_plugin::__private::shared::__private::modes::global::_ListedGenericInstance<#instances>
+                        for #instances_ident
+                    {
+                    }
+                )*
+            });
+        }
+    }
+
+    // the generic impls only hold for the listed instances, the only ones generic entries run for
+    let listed_where_clause = if generics.type_params().next().is_none()
+        || params.auto_plugin.generics.is_empty()
+    {
+        where_clause.cloned()
+    } else {
+        let instances_ident = _generic_instances_ident(ident);
+        let mut generics = generics.clone();
+        generics.make_where_clause().predicates.push(syn::parse_quote!(
+                #instances_ident: ::bevy_auto_plugin::__private::shared::__private::modes::global::_ListedGenericInstance<#ident #ty_generics>
+            ));
+        generics.where_clause
+    };
+
+    let register_entries_fn = if build_script::_entries_file_enabled() {
+        quote! {
+            fn _register_entries() {
+                crate::AUTO_PLUGIN_ENTRIES.register();
+            }
+        }
+    } else {
+        MacroStream::new()
+    };
+
     output.extend(quote! {
         impl #impl_generics ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPluginTypeId
             for #ident #ty_generics #where_clause
@@ -204,6 +601,7 @@ pub fn expand_global_derive_global_auto_plugin(input: MacroStream) -> MacroStrea
             fn type_id() -> std::any::TypeId {
                 ::std::any::TypeId::of::<Self>()
             }
+            #register_entries_fn
         }
     });
 
@@ -233,9 +631,18 @@ pub fn expand_global_derive_global_auto_plugin(input: MacroStream) -> MacroStrea
                     fn build(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
                         <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::build(self, app);
                     }
+                    fn finish(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
+                        <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::finish(self, app);
+                    }
+                    fn cleanup(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
+                        <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::cleanup(self, app);
+                    }
+                    #is_unique_fn
                 }
 
-                impl ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin for #path_with_generics {}
+                impl ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin for #path_with_generics {
+                    #auto_plugin_body
+                }
             });
         }
     }
@@ -243,11 +650,18 @@ pub fn expand_global_derive_global_auto_plugin(input: MacroStream) -> MacroStrea
     if params.auto_plugin.impl_generic_plugin_trait {
         output.extend(quote! {
             impl #impl_generics ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::Plugin
-                for #ident #ty_generics #where_clause
+                for #ident #ty_generics #listed_where_clause
             {
                 fn build(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
                     <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::build(self, app);
                 }
+                fn finish(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
+                    <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::finish(self, app);
+                }
+                fn cleanup(&self, app: &mut ::bevy_auto_plugin::__private::shared::__private::modes::global::bevy_app::App) {
+                    <Self as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin>::cleanup(self, app);
+                }
+                #is_unique_fn
             }
         });
     }
@@ -256,8 +670,10 @@ pub fn expand_global_derive_global_auto_plugin(input: MacroStream) -> MacroStrea
     if params.auto_plugin.impl_generic_auto_plugin_trait {
         output.extend(quote! {
             impl #impl_generics ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin
-                for #ident #ty_generics #where_clause
-            {}
+                for #ident #ty_generics #listed_where_clause
+            {
+                #auto_plugin_body
+            }
         });
     } else if !auto_plugin_implemented {
         auto_plugin_implemented = true;
@@ -281,7 +697,9 @@ pub fn expand_global_derive_global_auto_plugin(input: MacroStream) -> MacroStrea
             auto_plugin_implemented = true;
 
             output.extend(quote! {
-                impl ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin for #path_with_generics {}
+                impl ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPlugin for #path_with_generics {
+                    #auto_plugin_body
+                }
             });
         }
     }
@@ -322,27 +740,66 @@ pub fn global_auto_add_observer_outer(attr: MacroStream, input: MacroStream) ->
     global_attribute_outer::<GlobalArgs<AddObserverAttributeArgs>>(attr, input)
 }
 
-fn global_auto_inner<T: ShortHandAttribute + FromMeta>(
+fn global_auto_inner<T: ShortHandAttribute + AttributeKeys + AutoPluginAttributeKind + FromMeta>(
     attr: MacroStream,
     input: MacroStream,
 ) -> syn::Result<MacroStream> {
     use crate::__private::attribute_args::GlobalArgs;
     use crate::__private::attribute_args::attributes::shorthand::Mode;
-    let args = parse2::<GlobalArgs<T>>(attr)?;
-    let args_ts = args.inner.expand_attrs(&Mode::Global {
-        plugin: args.plugin,
+    let attribute = T::attribute().ident_str();
+    let attr = match expand_generics_args(attribute, attr, &input)? {
+        ExpandedGenericsArgs::Args(attr) => attr,
+        ExpandedGenericsArgs::Deferred(output) => return Ok(output),
+    };
+    let args =
+        parse_args_with_valid_keys::<GlobalArgs<T>, T>(attr, attribute, "global", GLOBAL_KEYS)?;
+    let mut args_ts = args.inner.expand_attrs(&Mode::Global {
+        plugin: args.plugin.clone(),
     });
+    let forwarded_args = global_args_to_forward(&args);
+    if !forwarded_args.is_empty() {
+        args_ts.attrs = args_ts
+            .attrs
+            .into_iter()
+            .map(|attr_ts| {
+                let mut attrs = syn::Attribute::parse_outer.parse2(attr_ts)?;
+                for (key, arg) in &forwarded_args {
+                    inject_arg_for_attributes(&mut attrs, key, arg);
+                }
+                Ok(quote! { #(#attrs)* })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+    }
     Ok(quote! {
         #args_ts
         #input
     })
 }
 
-fn global_auto_outer<T: ShortHandAttribute + FromMeta>(
+/// Global-only args that need to be carried over to the `auto_*` attributes a macro expands into
+fn global_args_to_forward<T>(args: &GlobalArgs<T>) -> Vec<(&'static str, MacroStream)> {
+    let mut forwarded_args = vec![];
+    if !args.phase.is_default() {
+        forwarded_args.push(("phase", args.phase.to_arg_tokens()));
+    }
+    if let Some(sub_app) = &args.sub_app {
+        forwarded_args.push(("sub_app", quote! { sub_app = #sub_app }));
+    }
+    if let Some(group) = &args.group {
+        forwarded_args.push(("group", quote! { group = #group }));
+    }
+    forwarded_args
+}
+
+fn global_auto_outer<T: ShortHandAttribute + AttributeKeys + AutoPluginAttributeKind + FromMeta>(
     attr: MacroStream,
     input: MacroStream,
 ) -> MacroStream {
-    global_auto_inner::<T>(attr, input).unwrap_or_else(|err| err.to_compile_error())
+    // keep the item on errors so uses of it elsewhere still resolve
+    global_auto_inner::<T>(attr, input.clone()).unwrap_or_else(|err| {
+        let err = err.to_compile_error();
+        quote! { #input #err }
+    })
 }
 
 pub fn global_auto_component(attr: MacroStream, input: MacroStream) -> MacroStream {
@@ -370,22 +827,28 @@ pub fn global_auto_bind_plugin_inner(
 ) -> syn::Result<MacroStream> {
     use crate::__private::attribute_args::GlobalArgs;
     use crate::__private::util::extensions::item::ItemAttrsExt;
-    use proc_macro2::Span;
     use quote::quote;
     use syn::Item;
 
     let mut item = parse2::<Item>(input)?;
-    let args = parse2::<GlobalArgs<()>>(attr)?;
-    let plugin = args.plugin;
+    let args =
+        parse2::<GlobalArgs<()>>(attr).map_err(|err| prefix_errors("failed to parse", err))?;
+    let plugin = &args.plugin;
 
-    let Ok(mut attrs) = item.take_attrs() else {
+    let (Item::Fn(_) | Item::Struct(_) | Item::Enum(_)) = item else {
         return Err(syn::Error::new(
-            Span::call_site(),
+            item_span(&item),
             "auto_bind_plugin supports only functions, structs, or enums",
         ));
     };
+    let Ok(mut attrs) = item.take_attrs() else {
+        unreachable!()
+    };
 
-    inject_plugin_arg_for_attributes(&mut attrs, &plugin);
+    inject_arg_for_attributes(&mut attrs, "plugin", &quote! { plugin = #plugin });
+    for (key, arg) in global_args_to_forward(&args) {
+        inject_arg_for_attributes(&mut attrs, key, &arg);
+    }
 
     let Ok(_) = item.put_attrs(attrs) else {
         unreachable!()
@@ -395,10 +858,43 @@ pub fn global_auto_bind_plugin_inner(
 }
 
 pub fn global_auto_bind_plugin_outer(attr: MacroStream, input: MacroStream) -> MacroStream {
-    global_auto_bind_plugin_inner(attr, input).unwrap_or_else(|err| err.to_compile_error())
+    // keep the item on errors so uses of it elsewhere still resolve
+    global_auto_bind_plugin_inner(attr, input.clone()).unwrap_or_else(|err| {
+        let err = err.to_compile_error();
+        quote! { #input #err }
+    })
+}
+
+/// Expansion of the global attribute macro named `name`, for expanding sources outside of the compiler
+/// (`bevy_auto_plugin_build`). `auto_plugin` is only the attribute on fns, on structs it's the derive's helper.
+pub fn global_attribute_expander(
+    name: &str,
+) -> Option<fn(MacroStream, MacroStream) -> MacroStream> {
+    Some(match name {
+        "auto_plugin" => expand_global_auto_plugin,
+        "auto_register_type" => global_auto_register_type_outer,
+        "auto_add_event" => global_auto_add_event_outer,
+        "auto_init_resource" => global_auto_init_resource_outer,
+        "auto_insert_resource" => global_auto_insert_resource_outer,
+        "auto_init_state" => global_auto_init_state_outer,
+        "auto_name" => global_auto_name_outer,
+        "auto_register_state_type" => global_auto_register_state_type_outer,
+        "auto_add_system" => global_auto_add_system_outer,
+        "auto_add_observer" => global_auto_add_observer_outer,
+        "auto_component" => global_auto_component,
+        "auto_resource" => global_auto_resource,
+        "auto_event" => global_auto_event,
+        "auto_states" => global_auto_states,
+        "auto_system" => global_auto_system,
+        "auto_observer" => global_auto_observer,
+        "auto_bind_plugin" => global_auto_bind_plugin_outer,
+        "auto_apply_preset" => global_auto_apply_preset_outer,
+        "auto_preset_expand" => global_auto_preset_expand_outer,
+        _ => return None,
+    })
 }
 
-fn inject_plugin_arg_for_attributes(attrs: &mut Vec<syn::Attribute>, plugin: &syn::Path) {
+fn inject_arg_for_attributes(attrs: &mut Vec<syn::Attribute>, key: &str, arg: &MacroStream) {
     use syn::Meta;
 
     for attr in attrs {
@@ -413,32 +909,32 @@ fn inject_plugin_arg_for_attributes(attrs: &mut Vec<syn::Attribute>, plugin: &sy
             continue;
         }
 
-        let already_has_plugin = match &attr.meta {
-            Meta::List(ml) => list_has_key(ml, "plugin"),
+        let already_has_arg = match &attr.meta {
+            Meta::List(ml) => list_has_key(ml, key),
             Meta::Path(_) => false,
             Meta::NameValue(_) => true,
         };
 
-        if already_has_plugin {
+        if already_has_arg {
             continue;
         }
 
-        inject_plugin_arg(attr, plugin);
+        inject_arg(attr, arg);
     }
 }
 
-fn inject_plugin_arg(attr: &mut syn::Attribute, plugin: &syn::Path) {
+fn inject_arg(attr: &mut syn::Attribute, arg: &MacroStream) {
     use syn::Meta;
     use syn::parse_quote;
     match &attr.meta {
-        Meta::Path(path) => *attr = parse_quote!( #[#path(plugin = #plugin)] ),
+        Meta::Path(path) => *attr = parse_quote!( #[#path(#arg)] ),
         Meta::List(ml) => {
             let path = &ml.path;
             let inner = &ml.tokens;
             if inner.is_empty() {
-                *attr = parse_quote!( #[#path(plugin = #plugin)] )
+                *attr = parse_quote!( #[#path(#arg)] )
             } else {
-                *attr = parse_quote!( #[#path(plugin = #plugin, #inner)] )
+                *attr = parse_quote!( #[#path(#arg, #inner)] )
             }
         }
         _ => {}
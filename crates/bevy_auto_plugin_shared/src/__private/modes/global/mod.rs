@@ -1,63 +1,103 @@
 // derived from Bevy Butler - MIT/Apache 2.0 https://github.com/TGRCdev/bevy-butler/blob/4eca26421d275134e0adc907e8c851bdcf10823a/bevy-butler/src/__private/plugin.rs
 
+pub mod audit;
+pub mod build_script;
+pub mod checks;
+pub mod export;
+pub mod filter;
 pub mod inner;
+pub mod stats;
+pub mod system_chain;
 
+pub use audit::{AuditAction, AutoPluginAuditPlugin, OrphanedEntry, orphaned_entries};
+pub use export::RegistryExport;
+pub use filter::{AutoPluginEntryKind, AutoPluginFilter};
+pub use stats::{AutoPluginBuildStats, AutoPluginBuildSummaryPlugin, PluginBuildStats};
+pub use system_chain::{AutoPluginSystemChainExt, SystemChain, SystemChainStep};
+
+use crate::__private::phase::PluginPhase;
+use bevy_ecs::prelude::{Bundle, Component, Event, Resource};
+use bevy_ecs::system::IntoObserverSystem;
+use bevy_platform::time::Instant;
 use proc_macro2::{Ident, TokenStream as MacroStream};
-use quote::quote;
-use std::any::{TypeId, type_name};
-use std::collections::HashMap;
-use std::sync::LazyLock;
-use syn::{ExprClosure, Path};
+use quote::{ToTokens, quote};
+use std::any::{Any, TypeId, type_name};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use syn::{ExprClosure, Generics, Path, PathArguments};
 
 pub use bevy_app;
 pub use bevy_log;
-#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+#[cfg(all(
+    any(target_arch = "wasm32", feature = "inventory"),
+    not(feature = "build_script")
+))]
 pub use inventory;
-#[cfg(all(not(target_arch = "wasm32"), not(feature = "inventory")))]
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "inventory"),
+    not(feature = "build_script")
+))]
 pub use linkme;
 
-#[cfg(all(not(target_arch = "wasm32"), not(feature = "inventory")))]
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "inventory"),
+    not(feature = "build_script")
+))]
 #[linkme::distributed_slice]
 pub static GLOBAL_AUTO_PLUGINS: [GlobalAutoPluginRegistryEntryFactory];
 
-#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+#[cfg(all(
+    any(target_arch = "wasm32", feature = "inventory"),
+    not(feature = "build_script")
+))]
 inventory::collect!(GlobalAutoPluginRegistryEntryFactory);
 
-pub static GLOBAL_AUTO_PLUGIN_REGISTRY: LazyLock<GlobalAutoPluginRegistry> = LazyLock::new(|| {
+pub static GLOBAL_AUTO_PLUGIN_REGISTRY: std::sync::LazyLock<GlobalAutoPluginRegistry> =
+    std::sync::LazyLock::new(|| {
+        #[cfg(not(feature = "build_script"))]
+        let factories = section_factories();
+        // only the generated files list entries, see [`build_script`]
+        #[cfg(feature = "build_script")]
+        let factories = std::iter::empty();
+
+        GlobalAutoPluginRegistry::from_factories(factories)
+    });
+
+/// Every entry in the `linkme` or `inventory` sections
+#[cfg(not(feature = "build_script"))]
+fn section_factories() -> impl Iterator<Item = &'static GlobalAutoPluginRegistryEntryFactory> {
+    // the constructors submit the `inventory` entries, so they only run once
     #[cfg(target_arch = "wasm32")]
-    crate::_initialize();
+    {
+        static INITIALIZED: std::sync::Once = std::sync::Once::new();
+        INITIALIZED.call_once(crate::_initialize);
+    }
 
     #[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
     let iter = GLOBAL_AUTO_PLUGINS.into_iter();
     #[cfg(any(target_arch = "wasm32", feature = "inventory"))]
     let iter = ::inventory::iter::<GlobalAutoPluginRegistryEntryFactory>.into_iter();
 
-    let mut count = 0;
-    let mut registry: HashMap<TypeId, Vec<BevyAppBuildFn>> = HashMap::new();
-    iter.for_each(
-        |GlobalAutoPluginRegistryEntryFactory(type_factory, sys_factory)| {
-            registry
-                .entry(type_factory())
-                .or_default()
-                .push(*sys_factory);
-            count += 1;
-        },
-    );
-
-    // Trim down
-    registry.values_mut().for_each(|vec| vec.shrink_to_fit());
-    registry.shrink_to_fit();
-
-    bevy_log::debug!("Building GlobalAutoPluginRegistry from {count} entries");
+    iter
+}
 
-    GlobalAutoPluginRegistry(registry)
-});
+/// The registry of every entry linked into the binary,
+/// merged with the [`build_script::BuildScriptEntries`] registered so far
+pub fn global_registry() -> &'static GlobalAutoPluginRegistry {
+    build_script::registry().unwrap_or(&GLOBAL_AUTO_PLUGIN_REGISTRY)
+}
 
 pub trait AutoPluginTypeId {
     fn type_id() -> TypeId;
+    /// Adds the entries generated for the plugin's crate to the registry, see [`build_script`]
+    #[doc(hidden)]
+    fn _register_entries() {}
 }
 
-pub trait AutoPlugin: bevy_app::Plugin + AutoPluginTypeId {
+pub trait AutoPlugin: bevy_app::Plugin + AutoPluginTypeId + Sized {
     fn name(&self) -> &'static str {
         Self::static_name()
     }
@@ -65,70 +105,721 @@ pub trait AutoPlugin: bevy_app::Plugin + AutoPluginTypeId {
         type_name::<Self>()
     }
     fn build(&self, app: &mut bevy_app::App) {
-        Self::static_build(app);
+        self.run_phase(app, PluginPhase::Build);
     }
     fn static_build(app: &mut bevy_app::App) {
+        Self::static_run_phase(app, PluginPhase::Build);
+    }
+    fn finish(&self, app: &mut bevy_app::App) {
+        self.run_phase(app, PluginPhase::Finish);
+    }
+    fn static_finish(app: &mut bevy_app::App) {
+        Self::static_run_phase(app, PluginPhase::Finish);
+    }
+    fn cleanup(&self, app: &mut bevy_app::App) {
+        self.run_phase(app, PluginPhase::Cleanup);
+    }
+    fn static_cleanup(app: &mut bevy_app::App) {
+        Self::static_run_phase(app, PluginPhase::Cleanup);
+    }
+    /// Runs the entries for `phase` with access to this plugin instance
+    fn run_phase(&self, app: &mut bevy_app::App, phase: PluginPhase) {
+        <Self as AutoPluginTypeId>::_register_entries();
         let type_id = <Self as AutoPluginTypeId>::type_id();
-        GLOBAL_AUTO_PLUGIN_REGISTRY
-            .get_entries(type_id)
-            .iter()
-            .for_each(|build_fn| {
-                build_fn(app);
+        let name = Self::static_name();
+        run_entries(type_id, name, app, self, phase, None);
+    }
+    /// Runs the entries for `phase` without a plugin instance,
+    /// entries that read from the plugin (e.g. `from_plugin`) will panic
+    fn static_run_phase(app: &mut bevy_app::App, phase: PluginPhase) {
+        <Self as AutoPluginTypeId>::_register_entries();
+        let type_id = <Self as AutoPluginTypeId>::type_id();
+        let name = Self::static_name();
+        run_entries(type_id, name, app, &(), phase, None);
+    }
+    /// Runs only the build entries matching `filter` that didn't run yet. The rest still run with
+    /// another filter or a later full build, but nothing runs once the full build did
+    fn build_filtered(&self, app: &mut bevy_app::App, filter: &AutoPluginFilter) {
+        <Self as AutoPluginTypeId>::_register_entries();
+        let type_id = <Self as AutoPluginTypeId>::type_id();
+        let name = Self::static_name();
+        let phase = PluginPhase::Build;
+        run_entries(type_id, name, app, self, phase, Some(filter));
+    }
+    /// [`AutoPlugin::build_filtered`] without a plugin instance
+    fn static_build_filtered(app: &mut bevy_app::App, filter: &AutoPluginFilter) {
+        <Self as AutoPluginTypeId>::_register_entries();
+        let type_id = <Self as AutoPluginTypeId>::type_id();
+        let name = Self::static_name();
+        let phase = PluginPhase::Build;
+        run_entries(type_id, name, app, &(), phase, Some(filter));
+    }
+}
+
+/// Runs the entries of a plugin for `phase` that didn't run in this app yet.
+///
+/// An unfiltered run completes the phase, later runs of it are skipped with a warning, whether they're
+/// through the plugin instance or `static_*`. A filtered run only marks the entries it ran, so other
+/// filters and a later full run still run the rest.
+fn run_entries(
+    type_id: TypeId,
+    name: &'static str,
+    app: &mut bevy_app::App,
+    plugin: &dyn Any,
+    phase: PluginPhase,
+    filter: Option<&AutoPluginFilter>,
+) {
+    let mut built = app.world_mut().get_resource_or_init::<BuiltAutoPlugins>();
+    build_script::warn_late_entries(&mut built);
+    if built.ran.contains(&(type_id, phase)) {
+        bevy_log::warn!(
+            "`{name}` already ran its {} entries in this app, skipping",
+            phase.as_str()
+        );
+        return;
+    }
+    if filter.is_none() {
+        built.ran.insert((type_id, phase));
+    }
+    let entries = global_registry()
+        .get_entries(type_id, phase)
+        .filter(|(ix, _)| !built.ran_entries.contains(&(type_id, *ix)))
+        .filter(|(_, entry)| filter.is_none_or(|filter| entry.factory.matches(filter)))
+        .collect::<Vec<_>>();
+    if filter.is_some() {
+        built
+            .ran_entries
+            .extend(entries.iter().map(|(ix, _)| (type_id, *ix)));
+    }
+    if phase == PluginPhase::Build {
+        if !built.plugins.iter().any(|(id, _)| *id == type_id) {
+            built.plugins.push((type_id, name));
+        }
+        // plugins added by another plugin's entries (e.g. `auto_plugin_entry!` calling `add_plugins`)
+        if let Some(&parent) = built.building.last() {
+            built.sub_plugins.push((parent, name));
+        }
+        built.building.push(name);
+    }
+    // spans and timings only once the app collects them, see `AutoPluginBuildSummaryPlugin`
+    let mut stats = app
+        .world()
+        .contains_resource::<AutoPluginBuildStats>()
+        .then(|| PluginBuildStats::new(name, phase));
+    match &mut stats {
+        None => entries
+            .into_iter()
+            .for_each(|(_, entry)| (entry.build_fn)(app, plugin)),
+        Some(stats) => {
+            let _span = bevy_log::info_span!("auto_plugin", plugin = name, phase = phase.as_str())
+                .entered();
+            let start = Instant::now();
+            entries.into_iter().for_each(|(_, entry)| {
+                let kind = entry.factory.kind;
+                let target = entry.factory.target.unwrap_or_default();
+                let _span =
+                    bevy_log::debug_span!("auto_plugin_entry", kind = kind.as_str(), target)
+                        .entered();
+                let entry_start = Instant::now();
+                (entry.build_fn)(app, plugin);
+                let elapsed = entry_start.elapsed();
+                bevy_log::trace!(?elapsed, "ran entry");
+                stats.record_entry(kind, target, elapsed);
             });
+            stats.elapsed = start.elapsed();
+        }
+    }
+    if phase == PluginPhase::Build {
+        app.world_mut()
+            .resource_mut::<BuiltAutoPlugins>()
+            .building
+            .pop();
+    }
+    if let Some(stats) = stats {
+        bevy_log::debug!(entries = stats.entries, elapsed = ?stats.elapsed, "ran {name} {} entries", phase.as_str());
+        app.world_mut()
+            .get_resource_or_init::<AutoPluginBuildStats>()
+            .push(stats);
+    }
+}
+
+/// The [`AutoPlugin`]s and phases that already ran their entries in this app
+#[doc(hidden)]
+#[derive(Resource, Debug, Default)]
+pub struct BuiltAutoPlugins {
+    /// Phases completed by an unfiltered run
+    ran: HashSet<(TypeId, PluginPhase)>,
+    /// Entries, by their index in the plugin's registry entries, that ran through a filtered run
+    ran_entries: HashSet<(TypeId, usize)>,
+    /// Plugins in the order their build entries ran
+    plugins: Vec<(TypeId, &'static str)>,
+    /// Names of the plugins whose build entries are currently running, innermost last
+    building: Vec<&'static str>,
+    /// `(parent, child)` names of plugins built while running the parent's build entries
+    sub_plugins: Vec<(&'static str, &'static str)>,
+    /// Registered [`build_script::BuildScriptEntries`] already checked for entries of built plugins
+    checked_sources: usize,
+}
+
+impl BuiltAutoPlugins {
+    /// Whether the plugin's build entries ran, or some of them through a filtered build
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.plugins.iter().any(|(id, _)| *id == type_id)
+    }
+    pub fn plugins(&self) -> &[(TypeId, &'static str)] {
+        &self.plugins
+    }
+    pub fn sub_plugins(&self) -> &[(&'static str, &'static str)] {
+        &self.sub_plugins
+    }
+}
+
+/// Downcasts the plugin instance passed to registry entries
+pub fn _downcast_plugin<P: Any>(plugin: &dyn Any) -> &P {
+    plugin.downcast_ref::<P>().unwrap_or_else(|| {
+        panic!(
+            "entry requires the `{}` plugin instance, but the plugin was built without one (e.g. through `AutoPlugin::static_build`)",
+            type_name::<P>()
+        )
+    })
+}
+
+/// Implemented for the plugin of a bare fn `#[auto_plugin(plugin = ..)]`, which builds without an instance
+#[doc(hidden)]
+pub trait _PluginBuiltWithoutInstance {}
+
+/// Rejects entries reading the instance of a [`_PluginBuiltWithoutInstance`] plugin at compile time.
+///
+/// `probe()` resolves to the inherent method only when `P` is known to be built without an instance,
+/// and to [`_PluginInstanceProbeFallback`] otherwise (including generic entries, where `P` isn't known)
+#[doc(hidden)]
+pub struct _PluginInstanceProbe<P>(pub PhantomData<P>);
+
+impl<P> _PluginInstanceProbe<P> {
+    /// Probes the plugin `f` reads, e.g. a `from_plugin` fn
+    pub fn of<R>(_: &impl FnOnce(&P) -> R) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: _PluginBuiltWithoutInstance> _PluginInstanceProbe<P> {
+    pub fn probe(&self) -> _NoPluginInstance<P> {
+        _NoPluginInstance(PhantomData)
     }
 }
 
+#[doc(hidden)]
+pub trait _PluginInstanceProbeFallback {
+    fn probe(&self) -> _HasPluginInstance {
+        _HasPluginInstance
+    }
+}
+
+impl<P> _PluginInstanceProbeFallback for _PluginInstanceProbe<P> {}
+
+#[doc(hidden)]
+pub struct _NoPluginInstance<P>(PhantomData<P>);
+
+#[doc(hidden)]
+pub struct _HasPluginInstance;
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{P}` is built by a bare fn `#[auto_plugin]` without a plugin instance to read",
+    label = "reads the `{P}` instance",
+    note = "derive `AutoPlugin` with `#[auto_plugin(impl_plugin_trait)]` instead, or implement `Plugin` calling `AutoPlugin::build(self, app)`"
+)]
+pub trait _RequiresPluginInstance<P> {}
+
+impl<P> _RequiresPluginInstance<P> for _HasPluginInstance {}
+
+/// `_require_plugin_instance(&probe, probe.probe())`, see [`_PluginInstanceProbe`]
+pub fn _require_plugin_instance<P, T: _RequiresPluginInstance<P>>(
+    _: &_PluginInstanceProbe<P>,
+    _: T,
+) {
+}
+
+/// Calls `f` with the plugin instance passed to registry entries
+pub fn _from_plugin<P: Any, R>(plugin: &dyn Any, f: impl FnOnce(&P) -> R) -> R {
+    f(_downcast_plugin(plugin))
+}
+
 pub type TypeIdFn = fn() -> TypeId;
-pub type BevyAppBuildFn = fn(&mut bevy_app::App);
-pub struct GlobalAutoPluginRegistryEntryFactory(TypeIdFn, BevyAppBuildFn);
+pub type TypeNameFn = fn() -> &'static str;
+pub type BevyAppBuildFn = fn(&mut bevy_app::App, &dyn Any);
+/// Reports the build fn of a generic entry for every concrete plugin (`TypeId` and type name) it's instantiated for
+pub type GenericInstancesFn = fn(&mut dyn FnMut(TypeId, &'static str, BevyAppBuildFn));
+
+/// Implemented by generic entries (`plugin = MyPlugin<T>, generics(T)`) for each concrete plugin `P`
+pub trait GenericAutoPluginEntry<P> {
+    fn build_fn() -> BevyAppBuildFn;
+}
+
+/// Implemented by the `AutoPlugin` derive on a generic plugin's instances type for every instance `P` listed in its
+/// `generics(..)`, which bounds the plugin's generic `Plugin`/`AutoPlugin` impls
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{P}` isn't listed in the `generics(..)` of its `#[auto_plugin(..)]`",
+    label = "not a listed instance",
+    note = "generic entries are only instantiated for the listed instances, so the plugin can't be built for any other type (e.g. one from a downstream crate)"
+)]
+pub trait _ListedGenericInstance<P> {}
+
+/// Implemented by the `AutoPlugin` derive for the instances type of a generic plugin listing `generics(..)`
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the plugin doesn't list any `generics(..)` in its `#[auto_plugin(..)]` to instantiate generic entries for",
+    label = "generic entry for a plugin without listed instances",
+    note = "list the instances, e.g. `#[auto_plugin(generics(u8))]`, or bind the entry to a concrete plugin"
+)]
+pub trait _HasListedGenerics<V> {}
+
+enum EntryTarget {
+    Plugin {
+        type_factory: TypeIdFn,
+        type_name: TypeNameFn,
+        build_fn: BevyAppBuildFn,
+    },
+    Generic(GenericInstancesFn),
+}
+
+/// Where a registry entry was declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntrySource {
+    /// The `plugin = ..` path as written
+    pub plugin: &'static str,
+    /// `module_path!()` where the entry is declared
+    pub module: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Display for EntrySource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+pub struct GlobalAutoPluginRegistryEntryFactory {
+    plugins: EntryTarget,
+    phase: PluginPhase,
+    kind: AutoPluginEntryKind,
+    group: Option<&'static str>,
+    target: Option<&'static str>,
+    ordering: &'static [(&'static str, &'static str)],
+    source: Option<EntrySource>,
+}
 
 impl GlobalAutoPluginRegistryEntryFactory {
-    pub const fn new(type_factory: fn() -> TypeId, sys_factory: fn(&mut bevy_app::App)) -> Self {
-        Self(type_factory, sys_factory)
+    pub const fn new(
+        type_factory: TypeIdFn,
+        type_name: TypeNameFn,
+        sys_factory: BevyAppBuildFn,
+    ) -> Self {
+        Self {
+            plugins: EntryTarget::Plugin {
+                type_factory,
+                type_name,
+                build_fn: sys_factory,
+            },
+            phase: PluginPhase::Build,
+            kind: AutoPluginEntryKind::Custom,
+            group: None,
+            target: None,
+            ordering: &[],
+            source: None,
+        }
+    }
+    pub const fn generic(instances: GenericInstancesFn) -> Self {
+        Self {
+            plugins: EntryTarget::Generic(instances),
+            phase: PluginPhase::Build,
+            kind: AutoPluginEntryKind::Custom,
+            group: None,
+            target: None,
+            ordering: &[],
+            source: None,
+        }
+    }
+    pub const fn with_phase(mut self, phase: PluginPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+    pub const fn with_kind(mut self, kind: AutoPluginEntryKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    pub const fn with_group(mut self, group: &'static str) -> Self {
+        self.group = Some(group);
+        self
+    }
+    pub const fn with_target(mut self, target: &'static str) -> Self {
+        self.target = Some(target);
+        self
+    }
+    pub const fn with_ordering(
+        mut self,
+        ordering: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        self.ordering = ordering;
+        self
+    }
+    pub const fn with_source(mut self, source: EntrySource) -> Self {
+        self.source = Some(source);
+        self
+    }
+    pub const fn phase(&self) -> PluginPhase {
+        self.phase
+    }
+    pub const fn kind(&self) -> AutoPluginEntryKind {
+        self.kind
+    }
+    pub const fn group(&self) -> Option<&'static str> {
+        self.group
+    }
+    /// The ident of the item the entry registers
+    pub const fn target(&self) -> Option<&'static str> {
+        self.target
+    }
+    /// Declared schedule and ordering constraints, e.g. `[("schedule", "Update"), ("after", "foo")]`
+    pub const fn ordering(&self) -> &'static [(&'static str, &'static str)] {
+        self.ordering
+    }
+    pub const fn source(&self) -> Option<EntrySource> {
+        self.source
+    }
+    fn matches(&self, filter: &AutoPluginFilter) -> bool {
+        filter.matches(self.kind, self.group)
+    }
+    fn for_each_instance(&self, f: &mut dyn FnMut(TypeId, &'static str, BevyAppBuildFn)) {
+        match &self.plugins {
+            EntryTarget::Plugin {
+                type_factory,
+                type_name,
+                build_fn,
+            } => f(type_factory(), type_name(), *build_fn),
+            EntryTarget::Generic(instances) => instances(f),
+        }
     }
 }
-pub struct GlobalAutoPluginRegistry(HashMap<TypeId, Vec<BevyAppBuildFn>>);
+
+/// A registry entry resolved to a concrete plugin
+pub(crate) struct GlobalAutoPluginRegistryEntry {
+    factory: &'static GlobalAutoPluginRegistryEntryFactory,
+    plugin_name: &'static str,
+    build_fn: BevyAppBuildFn,
+}
+
+pub struct GlobalAutoPluginRegistry(HashMap<TypeId, Vec<GlobalAutoPluginRegistryEntry>>);
 
 impl GlobalAutoPluginRegistry {
-    pub(crate) fn get_entries(&'static self, marker: TypeId) -> &'static [BevyAppBuildFn] {
+    pub(crate) fn from_factories(
+        factories: impl Iterator<Item = &'static GlobalAutoPluginRegistryEntryFactory>,
+    ) -> Self {
+        let mut count = 0;
+        let mut registry: HashMap<TypeId, Vec<GlobalAutoPluginRegistryEntry>> = HashMap::new();
+        factories.for_each(|factory| {
+            factory.for_each_instance(&mut |type_id, plugin_name, build_fn| {
+                registry
+                    .entry(type_id)
+                    .or_default()
+                    .push(GlobalAutoPluginRegistryEntry {
+                        factory,
+                        plugin_name,
+                        build_fn,
+                    });
+                count += 1;
+            });
+        });
+
+        // Trim down
+        registry.values_mut().for_each(|vec| vec.shrink_to_fit());
+        registry.shrink_to_fit();
+
+        bevy_log::debug!("Building GlobalAutoPluginRegistry from {count} entries");
+
+        Self(registry)
+    }
+    pub(crate) fn get_entries(
+        &'static self,
+        marker: TypeId,
+        phase: PluginPhase,
+    ) -> impl Iterator<Item = (usize, &'static GlobalAutoPluginRegistryEntry)> {
         self.0
             .get(&marker)
             .map(|v| v.as_slice())
             .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter(move |(_, entry)| entry.factory.phase == phase)
+    }
+    /// Every plugin `TypeId` and type name with its entry factories
+    pub(crate) fn iter(
+        &'static self,
+    ) -> impl Iterator<
+        Item = (
+            TypeId,
+            &'static str,
+            impl Iterator<Item = &'static GlobalAutoPluginRegistryEntryFactory>,
+        ),
+    > {
+        self.0.iter().map(|(type_id, entries)| {
+            let name = entries.first().map(|entry| entry.plugin_name);
+            (
+                *type_id,
+                name.unwrap_or_default(),
+                entries.iter().map(|entry| entry.factory),
+            )
+        })
+    }
+}
+
+/// [`bevy_app::App`] methods used by registry entries that [`bevy_app::SubApp`] doesn't provide
+pub trait AutoPluginSubAppExt {
+    fn add_observer<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self;
+    fn register_required_components_with<T: Component, R: Component>(
+        &mut self,
+        constructor: fn() -> R,
+    ) -> &mut Self;
+}
+
+impl AutoPluginSubAppExt for bevy_app::SubApp {
+    fn add_observer<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.world_mut().add_observer(observer);
+        self
+    }
+    fn register_required_components_with<T: Component, R: Component>(
+        &mut self,
+        constructor: fn() -> R,
+    ) -> &mut Self {
+        self.world_mut()
+            .register_required_components_with::<T, R>(constructor);
+        self
+    }
+}
+
+/// Registration body that runs `register` on the sub app with `label`, bound to `sub_app`, instead of the main app
+pub fn _sub_app_register_block(label: &Path, plugin: &Path, register: &MacroStream) -> MacroStream {
+    let message = format!(
+        "sub app `{}` not found while building `{}` - insert it before the plugin is added or use a later `phase`",
+        label.to_token_stream(),
+        plugin.to_token_stream(),
+    )
+    .replace(" :: ", "::")
+    .replace("{", "{{")
+    .replace("}", "}}");
+    quote! {
+        use ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPluginSubAppExt as _;
+        let sub_app = app.get_sub_app_mut(#label).unwrap_or_else(|| panic!(#message));
+        #register
     }
 }
 
-pub fn _plugin_entry_block(static_ident: &Ident, plugin: &Path, expr: &ExprClosure) -> MacroStream {
+/// `.with_source(..)` pointing at the macro invocation of the entry for `plugin`
+fn _entry_source_tokens(plugin: &Path) -> MacroStream {
+    let plugin = plugin.to_token_stream().to_string().replace(" ", "");
     quote! {
-        ::bevy_auto_plugin::__private::shared::_plugin_entry!(
-            #static_ident,
+        .with_source(::bevy_auto_plugin::__private::shared::__private::modes::global::EntrySource {
+            plugin: #plugin,
+            module: ::core::module_path!(),
+            file: ::core::file!(),
+            line: ::core::line!(),
+            column: ::core::column!(),
+        })
+    }
+}
+
+/// Metadata attached to a generated registry entry
+pub struct PluginEntryMeta<'a> {
+    pub phase: PluginPhase,
+    pub kind: AutoPluginEntryKind,
+    pub group: Option<&'a str>,
+    pub target: Option<&'a str>,
+    pub ordering: Vec<(&'static str, String)>,
+}
+
+impl ToTokens for PluginEntryMeta<'_> {
+    fn to_tokens(&self, tokens: &mut MacroStream) {
+        let Self {
+            phase,
+            kind,
+            group,
+            target,
+            ordering,
+        } = self;
+        tokens.extend(quote! { .with_phase(#phase).with_kind(#kind) });
+        if let Some(group) = group {
+            tokens.extend(quote! { .with_group(#group) });
+        }
+        if let Some(target) = target {
+            tokens.extend(quote! { .with_target(#target) });
+        }
+        if !ordering.is_empty() {
+            let ordering = ordering.iter().map(|(arg, path)| quote!((#arg, #path)));
+            tokens.extend(quote! { .with_ordering(&[#(#ordering),*]) });
+        }
+    }
+}
+
+/// Ident of the hidden type the `AutoPlugin` derive generates to list a generic plugin's concrete instances
+pub fn _generic_instances_ident(plugin_ident: &Ident) -> Ident {
+    quote::format_ident!("__AutoPluginGenerics{}", plugin_ident)
+}
+
+/// Entry for `plugin = MyPlugin<T>, generics(T)`, instantiated for every concrete `MyPlugin<..>`
+/// listed in the plugin's `#[auto_plugin(generics(..))]`
+pub fn _generic_plugin_entry_block(
+    static_ident: &Ident,
+    plugin: &Path,
+    generics: &Generics,
+    expr: &ExprClosure,
+    meta: &PluginEntryMeta,
+) -> syn::Result<MacroStream> {
+    let mut instances_path = plugin.clone();
+    let Some(last) = instances_path.segments.last_mut() else {
+        return Err(syn::Error::new_spanned(plugin, "expected a plugin path"));
+    };
+    last.ident = _generic_instances_ident(&last.ident);
+    last.arguments = PathArguments::None;
+    let instance_ident = quote::format_ident!("{}_instance", static_ident);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let source = _entry_source_tokens(plugin);
+    let entry = _plugin_entry_static(
+        static_ident,
+        quote! {
+            ::bevy_auto_plugin::__private::shared::__private::modes::global::GlobalAutoPluginRegistryEntryFactory::generic(
+                |add| #instances_path::instances::<#instance_ident>(add)
+            )#meta #source
+        },
+    );
+    Ok(quote! {
+        #[allow(non_camel_case_types)]
+        struct #instance_ident;
+
+        impl #impl_generics ::bevy_auto_plugin::__private::shared::__private::modes::global::GenericAutoPluginEntry<#plugin>
+            for #instance_ident #where_clause
+        {
+            fn build_fn() -> ::bevy_auto_plugin::__private::shared::__private::modes::global::BevyAppBuildFn {
+                #expr
+            }
+        }
+
+        #entry
+    })
+}
+
+pub fn _plugin_entry_block(
+    static_ident: &Ident,
+    plugin: &Path,
+    expr: &ExprClosure,
+    meta: &PluginEntryMeta,
+) -> MacroStream {
+    let source = _entry_source_tokens(plugin);
+    _plugin_entry_static(
+        static_ident,
+        quote! {
             ::bevy_auto_plugin::__private::shared::__private::modes::global::GlobalAutoPluginRegistryEntryFactory::new(
                 || <#plugin as ::bevy_auto_plugin::__private::shared::__private::modes::global::AutoPluginTypeId>::type_id(),
+                ::core::any::type_name::<#plugin>,
                 #expr
-            )
-        );
+            )#meta #source
+        },
+    )
+}
+
+/// The registry entry `static_ident`: in the `linkme`/`inventory` sections through `_plugin_entry!`, or when the crate
+/// generates its entries from `build.rs` (see [`build_script`]) a plain static implementing
+/// [`build_script::BuildScriptEntry`] for its `__BevyAutoPluginEntries`, keyed by the module path and `static_ident`,
+/// that fails to compile if the generated file doesn't list it
+fn _plugin_entry_static(static_ident: &Ident, entry: MacroStream) -> MacroStream {
+    if !build_script::_entries_file_enabled() {
+        return quote! {
+            ::bevy_auto_plugin::__private::shared::_plugin_entry!(#static_ident, #entry);
+        };
+    }
+    quote! {
+        #[allow(non_upper_case_globals)]
+        static #static_ident: ::bevy_auto_plugin::__private::shared::__private::modes::global::GlobalAutoPluginRegistryEntryFactory =
+            #entry;
+
+        #[allow(non_local_definitions)]
+        impl ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::BuildScriptEntry<
+            { ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::entry_id(
+                ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#static_ident))
+            ) }
+        > for crate::__BevyAutoPluginEntries {
+            const ENTRY: &'static ::bevy_auto_plugin::__private::shared::__private::modes::global::GlobalAutoPluginRegistryEntryFactory =
+                &#static_ident;
+        }
+
+        const _: () = ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::_require_listed::<
+            crate::__BevyAutoPluginEntries,
+            { ::bevy_auto_plugin::__private::shared::__private::modes::global::build_script::entry_id(
+                ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#static_ident))
+            ) },
+        >();
     }
 }
 
-#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "inventory"),
+    not(feature = "build_script")
+))]
 #[macro_export]
 #[doc(hidden)]
 macro_rules! _plugin_entry {
-        ($static_ident:ident, $entry:expr) => {
-            #[::bevy_auto_plugin::__private::shared::__private::modes::global::linkme::distributed_slice(::bevy_auto_plugin::__private::shared::__private::modes::global::GLOBAL_AUTO_PLUGINS)]
-            #[linkme(crate = ::bevy_auto_plugin::__private::shared::__private::modes::global::linkme)]
-            #[allow(non_upper_case_globals)]
-            static $static_ident:
-                ::bevy_auto_plugin::__private::shared::__private::modes::global::GlobalAutoPluginRegistryEntryFactory =
-                $entry;
-        };
-    }
+    ($static_ident:ident, $entry:expr) => {
+        #[$crate::__private::modes::global::linkme::distributed_slice(
+            $crate::__private::modes::global::GLOBAL_AUTO_PLUGINS
+        )]
+        #[linkme(crate = $crate::__private::modes::global::linkme)]
+        #[allow(non_upper_case_globals)]
+        static $static_ident:
+            $crate::__private::modes::global::GlobalAutoPluginRegistryEntryFactory = $entry;
+    };
+}
+
+#[cfg(all(
+    any(target_arch = "wasm32", feature = "inventory"),
+    not(feature = "build_script")
+))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _plugin_entry {
+    ($static_ident:ident, $entry:expr) => {
+        $crate::__private::modes::global::inventory::submit!($entry);
+    };
+}
 
-#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+/// Without linker sections only the entries of crates generating their `auto_plugins.rs` register,
+/// and those don't expand through `_plugin_entry!`
+#[cfg(feature = "build_script")]
 #[macro_export]
 #[doc(hidden)]
 macro_rules! _plugin_entry {
     ($static_ident:ident, $entry:expr) => {
-        ::bevy_auto_plugin::__private::shared::__private::modes::global::inventory::submit!($entry);
+        #[allow(non_upper_case_globals, dead_code)]
+        static $static_ident:
+            $crate::__private::modes::global::GlobalAutoPluginRegistryEntryFactory = $entry;
+    };
+}
+
+/// Includes the `auto_plugins.rs` generated by `bevy_auto_plugin_build::generate()`, at the crate root
+#[macro_export]
+macro_rules! include_auto_plugins {
+    () => {
+        ::core::include!(::core::env!(
+            "BEVY_AUTO_PLUGIN_ENTRIES_FILE",
+            "call `bevy_auto_plugin_build::generate()` from the crate's `build.rs` to generate `auto_plugins.rs`"
+        ));
     };
 }
@@ -0,0 +1,184 @@
+use super::AutoPluginEntryKind;
+use crate::__private::phase::PluginPhase;
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::Resource;
+use std::fmt::{Display, Formatter, Write};
+use std::time::Duration;
+
+/// Timing of one `AutoPlugin` phase in an app
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginBuildStats {
+    pub plugin: &'static str,
+    pub phase: PluginPhase,
+    pub entries: usize,
+    pub elapsed: Duration,
+    /// Number of entries that ran per kind
+    pub kinds: Vec<(AutoPluginEntryKind, usize)>,
+    /// The entry that took the longest, as `(kind, target, elapsed)`
+    pub slowest: Option<(AutoPluginEntryKind, &'static str, Duration)>,
+}
+
+impl PluginBuildStats {
+    pub fn new(plugin: &'static str, phase: PluginPhase) -> Self {
+        Self {
+            plugin,
+            phase,
+            entries: 0,
+            elapsed: Duration::ZERO,
+            kinds: Vec::new(),
+            slowest: None,
+        }
+    }
+    pub fn record_entry(
+        &mut self,
+        kind: AutoPluginEntryKind,
+        target: &'static str,
+        elapsed: Duration,
+    ) {
+        self.entries += 1;
+        match self.kinds.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, count)) => *count += 1,
+            None => self.kinds.push((kind, 1)),
+        }
+        if self.slowest.is_none_or(|(_, _, slowest)| elapsed > slowest) {
+            self.slowest = Some((kind, target, elapsed));
+        }
+    }
+}
+
+/// Every `AutoPlugin` phase that ran in this app, in the order they ran.
+///
+/// Only collected while the resource is in the app, e.g. inserted by [`AutoPluginBuildSummaryPlugin`]
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct AutoPluginBuildStats(Vec<PluginBuildStats>);
+
+impl AutoPluginBuildStats {
+    pub fn push(&mut self, stats: PluginBuildStats) {
+        self.0.push(stats);
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &PluginBuildStats> {
+        self.0.iter()
+    }
+    pub fn total_elapsed(&self) -> Duration {
+        self.0.iter().map(|stats| stats.elapsed).sum()
+    }
+}
+
+/// Formats the phases that ran entries as a table, slowest first
+impl Display for AutoPluginBuildStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut rows = self
+            .0
+            .iter()
+            .filter(|stats| stats.entries > 0)
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|stats| std::cmp::Reverse(stats.elapsed));
+        let width = rows
+            .iter()
+            .map(|stats| stats.plugin.len())
+            .max()
+            .unwrap_or_default()
+            .max("plugin".len());
+        writeln!(
+            f,
+            "{:<width$}  {:<7}  {:>7}  {:>10}  kinds / slowest entry",
+            "plugin", "phase", "entries", "elapsed"
+        )?;
+        for stats in rows {
+            let mut kinds = String::new();
+            for (i, (kind, count)) in stats.kinds.iter().enumerate() {
+                if i > 0 {
+                    kinds.push_str(", ");
+                }
+                write!(kinds, "{count} {}", kind.as_str())?;
+            }
+            if let Some((kind, target, elapsed)) = stats.slowest {
+                write!(kinds, " / {} `{target}` {elapsed:.1?}", kind.as_str())?;
+            }
+            writeln!(
+                f,
+                "{:<width$}  {:<7}  {:>7}  {:>10}  {kinds}",
+                stats.plugin,
+                stats.phase.as_str(),
+                stats.entries,
+                format!("{:.1?}", stats.elapsed),
+            )?;
+        }
+        write!(f, "total: {:.1?}", self.total_elapsed())
+    }
+}
+
+/// Collects [`AutoPluginBuildStats`] for the plugins added after it, and logs them as a table once every plugin
+/// is built (in [`Plugin::cleanup`])
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AutoPluginBuildSummaryPlugin;
+
+impl Plugin for AutoPluginBuildSummaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AutoPluginBuildStats>();
+    }
+
+    fn cleanup(&self, app: &mut App) {
+        if let Some(stats) = app.world().get_resource::<AutoPluginBuildStats>() {
+            bevy_log::info!("auto_plugin build summary:\n{stats}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[internal_test_proc_macro::xtest]
+    fn test_record_entry() {
+        let mut stats = PluginBuildStats::new("MyPlugin", PluginPhase::Build);
+        stats.record_entry(
+            AutoPluginEntryKind::AddSystem,
+            "foo",
+            Duration::from_micros(5),
+        );
+        stats.record_entry(
+            AutoPluginEntryKind::AddSystem,
+            "bar",
+            Duration::from_micros(20),
+        );
+        stats.record_entry(
+            AutoPluginEntryKind::InitResource,
+            "Baz",
+            Duration::from_micros(1),
+        );
+        assert_eq!(stats.entries, 3);
+        assert_eq!(
+            stats.kinds,
+            vec![
+                (AutoPluginEntryKind::AddSystem, 2),
+                (AutoPluginEntryKind::InitResource, 1)
+            ]
+        );
+        assert_eq!(
+            stats.slowest,
+            Some((
+                AutoPluginEntryKind::AddSystem,
+                "bar",
+                Duration::from_micros(20)
+            ))
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_table() {
+        let mut table = AutoPluginBuildStats::default();
+        let mut stats = PluginBuildStats::new("MyPlugin", PluginPhase::Build);
+        stats.record_entry(AutoPluginEntryKind::AddSystem, "foo", Duration::ZERO);
+        table.push(stats);
+        table.push(PluginBuildStats::new("MyPlugin", PluginPhase::Finish));
+        let table = table.to_string();
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3, "{table}");
+        assert!(lines[1].starts_with("MyPlugin  build"), "{table}");
+        assert!(
+            lines[1].ends_with("1 add_system / add_system `foo` 0.0ns"),
+            "{table}"
+        );
+    }
+}
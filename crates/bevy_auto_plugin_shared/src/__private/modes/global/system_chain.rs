@@ -0,0 +1,110 @@
+use bevy_app::{App, SubApp};
+use bevy_ecs::prelude::{IntoScheduleConfigs, Resource, SystemSet};
+use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy_ecs::system::ScheduleSystem;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Bound;
+
+/// Every system added with `chain = "name"`, configured by the members' `chain_config(..)`
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemChain(pub &'static str);
+
+/// The systems at one `chain_index` of a [`SystemChain`], running after the previous index
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemChainStep {
+    pub chain: &'static str,
+    pub index: u32,
+}
+
+/// The `chain_index`es added so far per schedule and chain
+#[derive(Resource, Debug, Default)]
+struct SystemChainSteps(HashMap<(InternedScheduleLabel, &'static str), BTreeSet<u32>>);
+
+/// Adds `chain = ".."` members, ordering each index between its neighbours as they're added
+/// so a chain can be declared across files and plugins
+pub trait AutoPluginSystemChainExt {
+    fn _add_chained_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        chain: &'static str,
+        index: u32,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self;
+}
+
+impl AutoPluginSystemChainExt for SubApp {
+    fn _add_chained_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        chain: &'static str,
+        index: u32,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self {
+        let schedule = schedule.intern();
+        let step = SystemChainStep { chain, index };
+        let neighbours = {
+            let mut steps = self.world_mut().get_resource_or_init::<SystemChainSteps>();
+            let indices = steps.0.entry((schedule, chain)).or_default();
+            indices.insert(index).then(|| {
+                let prev = indices.range(..index).next_back().copied();
+                let next = indices
+                    .range((Bound::Excluded(index), Bound::Unbounded))
+                    .next()
+                    .copied();
+                (prev, next)
+            })
+        };
+        if let Some((prev, next)) = neighbours {
+            self.configure_sets(schedule, step.in_set(SystemChain(chain)));
+            if let Some(prev) = prev {
+                self.configure_sets(schedule, step.after(SystemChainStep { chain, index: prev }));
+            }
+            if let Some(next) = next {
+                self.configure_sets(schedule, SystemChainStep { chain, index: next }.after(step));
+            }
+        }
+        self.add_systems(schedule, systems.in_set(step))
+    }
+}
+
+impl AutoPluginSystemChainExt for App {
+    fn _add_chained_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        chain: &'static str,
+        index: u32,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self {
+        self.main_mut()
+            ._add_chained_systems(schedule, chain, index, systems);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::Update;
+    use bevy_ecs::prelude::ResMut;
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<u32>);
+
+    fn push<const N: u32>(mut order: ResMut<Order>) {
+        order.0.push(N);
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_chain_order() {
+        let mut app = App::new();
+        app.init_resource::<Order>();
+        // added out of order and sparse, like members spread across files
+        app._add_chained_systems(Update, "test", 20, push::<20>)
+            ._add_chained_systems(Update, "test", 0, push::<0>)
+            ._add_chained_systems(Update, "test", 10, push::<10>)
+            ._add_chained_systems(Update, "other", 5, push::<5>)
+            .configure_sets(Update, SystemChain("other").run_if(|| false));
+        app.update();
+        assert_eq!(app.world().resource::<Order>().0, vec![0, 10, 20]);
+    }
+}
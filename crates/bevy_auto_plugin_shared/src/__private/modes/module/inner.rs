@@ -24,10 +24,16 @@ pub fn auto_plugin_inner(mut module: ItemMod, init_name: &Ident) -> syn::Result<
         let add_events = AddEventAttributeArgs::match_items(items)?;
         let init_resources = InitResourceAttributeArgs::match_items(items)?;
         let insert_resources = InsertResourceAttributeArgs::match_items(items)?;
+        for insert_resource in &insert_resources {
+            insert_resource.args.validate_no_from_plugin()?;
+        }
         let auto_names = AutoNameAttributeArgs::match_items(items)?;
         let register_state_types = RegisterStateTypeAttributeArgs::match_items(items)?;
         let init_states = InitStateAttributeArgs::match_items(items)?;
         let add_systems = AddSystemAttributeArgs::match_items(items)?;
+        for add_system in &add_systems {
+            add_system.args.schedule_config.validate_no_chain()?;
+        }
         let add_observers = AddObserverAttributeArgs::match_items(items)?;
 
         let mut context = AutoPluginContext::default();
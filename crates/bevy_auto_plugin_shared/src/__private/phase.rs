@@ -0,0 +1,113 @@
+use darling::{Error, FromMeta};
+use proc_macro2::TokenStream as MacroStream;
+use quote::{ToTokens, quote};
+use syn::Expr;
+
+/// The [`bevy_app::Plugin`] method a registry entry runs in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluginPhase {
+    /// [`bevy_app::Plugin::build`]
+    #[default]
+    Build,
+    /// [`bevy_app::Plugin::finish`]
+    Finish,
+    /// [`bevy_app::Plugin::cleanup`]
+    Cleanup,
+}
+
+impl PluginPhase {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Build => "build",
+            Self::Finish => "finish",
+            Self::Cleanup => "cleanup",
+        }
+    }
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+    /// `phase = finish` style tokens used when forwarding args to other attributes
+    pub fn to_arg_tokens(&self) -> MacroStream {
+        let ident = quote::format_ident!("{}", self.as_str());
+        quote! { phase = #ident }
+    }
+}
+
+impl FromMeta for PluginPhase {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Path(path) => match path.path.get_ident() {
+                Some(ident) => Self::from_string(&ident.to_string()),
+                None => Err(Error::unexpected_expr_type(expr)),
+            },
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "build" => Ok(Self::Build),
+            "finish" => Ok(Self::Finish),
+            "cleanup" => Ok(Self::Cleanup),
+            _ => Err(Error::unknown_value(value)),
+        }
+    }
+}
+
+impl ToTokens for PluginPhase {
+    fn to_tokens(&self, tokens: &mut MacroStream) {
+        let variant = match self {
+            Self::Build => quote!(Build),
+            Self::Finish => quote!(Finish),
+            Self::Cleanup => quote!(Cleanup),
+        };
+        tokens.extend(quote! {
+            ::bevy_auto_plugin::__private::shared::__private::phase::PluginPhase::#variant
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[derive(FromMeta, Debug, Default, PartialEq)]
+    #[darling(default)]
+    struct Args {
+        phase: PluginPhase,
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_from_meta() -> darling::Result<()> {
+        for (meta, expected) in [
+            (parse_quote!(foo(phase = build)), PluginPhase::Build),
+            (parse_quote!(foo(phase = finish)), PluginPhase::Finish),
+            (parse_quote!(foo(phase = cleanup)), PluginPhase::Cleanup),
+            (parse_quote!(foo(phase = "finish")), PluginPhase::Finish),
+            (parse_quote!(foo()), PluginPhase::Build),
+        ] {
+            let meta: syn::Meta = meta;
+            assert_eq!(Args::from_meta(&meta)?.phase, expected);
+        }
+        Ok(())
+    }
+
+    #[internal_test_proc_macro::xtest]
+    #[should_panic(expected = "value: \"startup\"")]
+    fn test_from_meta_unknown() {
+        let meta: syn::Meta = parse_quote!(foo(phase = startup));
+        Args::from_meta(&meta).unwrap();
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_to_arg_tokens() {
+        assert_eq!(
+            PluginPhase::Finish.to_arg_tokens().to_string(),
+            quote!(phase = finish).to_string()
+        );
+    }
+}
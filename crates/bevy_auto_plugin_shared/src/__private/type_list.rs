@@ -2,11 +2,14 @@ use crate::__private::util::generics_traits::{CountGenerics, HasGenericsCollecti
 use darling::{Error, FromMeta};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
+use syn::parse::ParseStream;
 use syn::parse::Parser;
-use syn::{Meta, Token, Type, punctuated::Punctuated};
+use syn::spanned::Spanned;
+use syn::{GenericArgument, GenericParam, Meta, Token, punctuated::Punctuated};
 
+/// The args of a `generics(..)` list: types, consts (`16`, `{ N * 2 }`) and lifetimes
 #[derive(Debug, Clone, Default, PartialEq, Hash)]
-pub struct TypeList(pub Vec<Type>);
+pub struct TypeList(pub Vec<GenericArgument>);
 
 impl TypeList {
     pub const fn empty() -> Self {
@@ -18,6 +21,82 @@ impl TypeList {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+    pub fn lifetimes(&self) -> impl Iterator<Item = &GenericArgument> {
+        self.0
+            .iter()
+            .filter(|arg| matches!(arg, GenericArgument::Lifetime(_)))
+    }
+    /// The type and const args, which may be given in any order
+    pub fn non_lifetimes(&self) -> impl Iterator<Item = &GenericArgument> {
+        self.0
+            .iter()
+            .filter(|arg| !matches!(arg, GenericArgument::Lifetime(_)))
+    }
+
+    /// Checks the args against the item's params, per kind: lifetimes may be left out altogether,
+    /// and a const value can't stand for a type param (or a type for a const param)
+    pub fn validate(&self, generics: &syn::Generics) -> syn::Result<()> {
+        let lifetimes = self.lifetimes().count();
+        let expected_lifetimes = generics.lifetimes().count();
+        if lifetimes != 0 && lifetimes != expected_lifetimes {
+            return Err(syn::Error::new(
+                self.get_span(),
+                format!("expected {expected_lifetimes} lifetime parameters, found {lifetimes}"),
+            ));
+        }
+        let params = generics
+            .params
+            .iter()
+            .filter(|param| !matches!(param, GenericParam::Lifetime(_)))
+            .collect::<Vec<_>>();
+        let args = self.non_lifetimes().collect::<Vec<_>>();
+        if args.len() != params.len() {
+            return Err(syn::Error::new(
+                self.get_span(),
+                format!(
+                    "expected {} generic parameters, found {}",
+                    params.len(),
+                    args.len()
+                ),
+            ));
+        }
+        for (param, arg) in params.into_iter().zip(args) {
+            match (param, arg) {
+                (GenericParam::Type(param), GenericArgument::Const(_)) => {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        format!("expected a type for `{}`, found a const value", param.ident),
+                    ));
+                }
+                // a lone ident is parsed as a type, but may just as well name a const
+                (GenericParam::Const(param), GenericArgument::Type(ty)) if !matches!(ty, syn::Type::Path(path) if path.qself.is_none() && path.path.get_ident().is_some()) =>
+                {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        format!("expected a const value for `{}`, found a type", param.ident),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `T, 16, { N * 2 }, 'static`, without associated type bindings or constraints
+fn parse_generic_arguments(input: ParseStream) -> syn::Result<Vec<GenericArgument>> {
+    Punctuated::<GenericArgument, Token![,]>::parse_terminated(input)?
+        .into_iter()
+        .map(|arg| match arg {
+            GenericArgument::Type(_) | GenericArgument::Const(_) | GenericArgument::Lifetime(_) => {
+                Ok(arg)
+            }
+            arg => Err(syn::Error::new(
+                arg.span(),
+                "expected a type, const value or lifetime",
+            )),
+        })
+        .collect()
 }
 
 impl ToTokens for TypeList {
@@ -47,20 +126,34 @@ impl From<TypeList> for TokenStream {
 impl FromMeta for TypeList {
     fn from_meta(meta: &Meta) -> Result<Self, Error> {
         let list = meta.require_list()?;
-        // Parse its tokens as `T, T, ...` where each `T` is a syn::Type
-        let parser = Punctuated::<Type, Token![,]>::parse_terminated;
-        let elems = parser.parse2(list.tokens.clone()).map_err(Error::custom)?;
-        Ok(TypeList(elems.into_iter().collect()))
+        let elems = parse_generic_arguments
+            .parse2(list.tokens.clone())
+            .map_err(Error::custom)?;
+        Ok(TypeList(elems))
     }
 }
 
 impl syn::parse::Parse for TypeList {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        use syn::{Token, Type, punctuated::Punctuated};
-        let elems = Punctuated::<Type, Token![,]>::parse_terminated(input)?
-            .into_iter()
-            .collect();
-        Ok(TypeList(elems))
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        parse_generic_arguments(input).map(TypeList)
+    }
+}
+
+impl CountGenerics for TypeList {
+    fn get_span(&self) -> Span {
+        self.span()
+    }
+
+    fn count_generics(&self) -> syn::Result<usize> {
+        Ok(self.len())
+    }
+}
+
+impl HasGenericsCollection for TypeList {
+    type CollectionItem = Self;
+    type Collection = Vec<Self>;
+    fn generics(&self) -> syn::Result<Self::Collection> {
+        Ok(vec![self.clone()])
     }
 }
 
@@ -84,7 +177,7 @@ mod tests {
 
         // The third element should be `Foo<u32>` with generics preserved.
         match &attr.types.0[2] {
-            Type::Path(tp) => {
+            GenericArgument::Type(Type::Path(tp)) => {
                 let seg = tp.path.segments.last().unwrap();
                 assert_eq!(seg.ident, "FooBar");
                 assert!(matches!(
@@ -99,23 +192,50 @@ mod tests {
         let tokens = quote! { #type_list };
         assert_eq!(tokens.to_string(), types.to_string());
     }
-}
 
-impl CountGenerics for TypeList {
-    fn get_span(&self) -> Span {
-        use syn::spanned::Spanned;
-        self.span()
-    }
-
-    fn count_generics(&self) -> syn::Result<usize> {
-        Ok(self.len())
+    #[internal_test_proc_macro::xtest]
+    fn parse_consts_and_lifetimes() -> syn::Result<()> {
+        let args = quote! { 'static, u8, 16, { N * 2 } };
+        let type_list = syn::parse2::<TypeList>(args.clone())?;
+        assert!(matches!(type_list.0[0], GenericArgument::Lifetime(_)));
+        assert!(matches!(type_list.0[1], GenericArgument::Type(_)));
+        assert!(matches!(type_list.0[2], GenericArgument::Const(_)));
+        assert!(matches!(type_list.0[3], GenericArgument::Const(_)));
+        assert_eq!(quote! { #type_list }.to_string(), args.to_string());
+        assert!(syn::parse2::<TypeList>(quote! { Item = u8 }).is_err());
+        Ok(())
     }
-}
 
-impl HasGenericsCollection for TypeList {
-    type CollectionItem = Self;
-    type Collection = Vec<Self>;
-    fn generics(&self) -> syn::Result<Self::Collection> {
-        Ok(vec![self.clone()])
+    #[internal_test_proc_macro::xtest]
+    fn validate_per_kind() -> syn::Result<()> {
+        let generics: syn::Generics = parse_quote!(<'a, T, const N: usize>);
+        let validate = |args: proc_macro2::TokenStream| {
+            syn::parse2::<TypeList>(args)?
+                .validate(&generics)
+                .map_err(|err| err.to_string())
+                .map_err(|err| syn::Error::new(Span::call_site(), err))
+        };
+        validate(quote! { u8, 16 })?;
+        validate(quote! { 'static, u8, { 4 * 2 } })?;
+        validate(quote! { u8, SIZE })?;
+        assert_eq!(
+            validate(quote! { u8 }).unwrap_err().to_string(),
+            "expected 2 generic parameters, found 1"
+        );
+        assert_eq!(
+            validate(quote! { 'static, 'static, u8, 16 })
+                .unwrap_err()
+                .to_string(),
+            "expected 1 lifetime parameters, found 2"
+        );
+        assert_eq!(
+            validate(quote! { 16, u8 }).unwrap_err().to_string(),
+            "expected a type for `T`, found a const value"
+        );
+        assert_eq!(
+            validate(quote! { u8, Vec<u8> }).unwrap_err().to_string(),
+            "expected a const value for `N`, found a type"
+        );
+        Ok(())
     }
 }
@@ -2,7 +2,6 @@ use crate::__private::attribute_args::{GenericsArgs, ItemAttributeArgs, WithTarg
 use crate::__private::generics::{Generics, GenericsCollection};
 use crate::__private::type_list::TypeList;
 use crate::__private::util::extensions::from_meta::FromMetaExt;
-use crate::__private::util::generics_traits::CountGenerics;
 use crate::__private::util::meta::IdentGenericsAttrsMeta;
 use crate::__private::util::path_fmt::{PathWithoutGenerics, TryFromPathWithoutGenericsError};
 use darling::FromMeta;
@@ -129,21 +128,21 @@ where
     ))
 }
 
-pub fn validate_generic_counts<T>(generics: &syn::Generics, cg: &T) -> syn::Result<()>
+/// Checks every `generics(..)` list against the item's params, per kind
+pub fn validate_generic_counts<T>(generics: &syn::Generics, args: &T) -> syn::Result<()>
 where
-    T: CountGenerics,
+    T: GenericsArgs,
 {
-    let expected_generics_count = generics.type_params().count();
-    if expected_generics_count > 0 {
-        let count = cg.count_generics()?;
-        if count != expected_generics_count {
-            return Err(syn::Error::new(
-                cg.get_span(),
-                format!("expected {expected_generics_count} generic parameters, found {count}"),
-            ));
-        }
+    if generics.params.is_empty() {
+        return Ok(());
+    }
+    let type_lists = args.type_lists();
+    if type_lists.is_empty() {
+        return TypeList::empty().validate(generics);
     }
-    Ok(())
+    type_lists
+        .iter()
+        .try_for_each(|type_list| type_list.validate(generics))
 }
 
 pub fn generics_from_path(path: &Path) -> syn::Result<TypeList> {
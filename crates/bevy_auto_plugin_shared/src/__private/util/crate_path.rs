@@ -0,0 +1,365 @@
+//! Resolves the path generated code uses to reach `bevy_auto_plugin`.
+//!
+//! Expansions are written against `::bevy_auto_plugin::..` and rewritten afterwards to either the
+//! name the dependency has in the calling crate's `Cargo.toml` or an explicit `crate = path`.
+
+use proc_macro_crate::{FoundCrate, crate_name};
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream as MacroStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Attribute, DeriveInput, LitStr, Meta, Path};
+
+/// Name of the crate generated code is written against
+pub const CRATE_NAME: &str = "bevy_auto_plugin";
+/// Key overriding the crate path on any attribute, e.g. `crate = ::game_core::bevy_auto_plugin`
+pub const CRATE_KEY: &str = "crate";
+
+/// Attributes an explicit `crate = path` is forwarded to when found in an expansion
+const FORWARD_TO: &[&str] = &[
+    "auto_add_event",
+    "auto_add_observer",
+    "auto_add_system",
+    "auto_bind_plugin",
+    "auto_component",
+    "auto_event",
+    "auto_init_resource",
+    "auto_init_state",
+    "auto_insert_resource",
+    "auto_name",
+    "auto_observer",
+    "auto_plugin",
+    "auto_preset_expand",
+    "auto_register_state_type",
+    "auto_register_type",
+    "auto_resource",
+    "auto_states",
+    "auto_system",
+];
+
+/// Where generated code finds `bevy_auto_plugin`
+#[derive(Debug, Clone, PartialEq)]
+pub enum CratePath {
+    /// `::bevy_auto_plugin`, nothing to rewrite
+    Default,
+    /// Found under another name in the calling crate's `Cargo.toml`
+    Renamed(Path),
+    /// Given with `crate = path`, also forwarded to the attributes the expansion emits
+    Override(Path),
+}
+
+impl CratePath {
+    /// Resolves the path from `override_path` or the calling crate's `Cargo.toml`.
+    ///
+    /// Falls back to `::bevy_auto_plugin` when the dependency isn't listed (e.g. it's only reachable through a re-export),
+    /// which then needs `crate = path`.
+    pub fn resolve(override_path: Option<Path>) -> Self {
+        if let Some(path) = override_path {
+            return Self::Override(path);
+        }
+        match crate_name(CRATE_NAME) {
+            Ok(FoundCrate::Name(name)) if name != CRATE_NAME => {
+                let ident = format_ident!("{}", name);
+                Self::Renamed(syn::parse_quote!(::#ident))
+            }
+            // `Itself` is only reported for our own lib, tests and doctests, where `::bevy_auto_plugin` resolves
+            _ => Self::Default,
+        }
+    }
+
+    /// Rewrites every `::bevy_auto_plugin` path root in `tokens`
+    pub fn rewrite(&self, tokens: MacroStream) -> MacroStream {
+        match self {
+            Self::Default => tokens,
+            Self::Renamed(path) => rewrite_crate_root(tokens, path, false),
+            Self::Override(path) => rewrite_crate_root(tokens, path, true),
+        }
+    }
+}
+
+/// Runs an attribute macro, taking `crate = path` out of its args and rewriting the expansion with it
+pub fn expand_attribute(
+    attr: MacroStream,
+    input: MacroStream,
+    expand: impl FnOnce(MacroStream, MacroStream) -> MacroStream,
+) -> MacroStream {
+    match take_crate_override(attr) {
+        Ok((attr, override_path)) => CratePath::resolve(override_path).rewrite(expand(attr, input)),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote! { #input #err }
+        }
+    }
+}
+
+/// Runs a function-like macro, taking `crate = path` out of its input and rewriting the expansion with it
+pub fn expand_fn_like(
+    input: MacroStream,
+    expand: impl FnOnce(MacroStream) -> MacroStream,
+) -> MacroStream {
+    match take_crate_override(input) {
+        Ok((input, override_path)) => CratePath::resolve(override_path).rewrite(expand(input)),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+/// Runs a derive macro, taking `crate = path` out of the `helper` attributes and rewriting the expansion with it
+pub fn expand_derive(
+    helper: &str,
+    input: MacroStream,
+    expand: impl FnOnce(MacroStream) -> MacroStream,
+) -> MacroStream {
+    let Ok(mut derive_input) = syn::parse2::<DeriveInput>(input.clone()) else {
+        return expand(input);
+    };
+    match take_crate_override_from_attrs(&mut derive_input.attrs, helper) {
+        Ok(None) => CratePath::resolve(None).rewrite(expand(input)),
+        Ok(Some(path)) => CratePath::resolve(Some(path)).rewrite(expand(quote! { #derive_input })),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+/// Removes a top level `crate = path` from comma separated args, returning the path if it was there.
+///
+/// The path may also be given as a string, `crate = "::game_core::bevy_auto_plugin"`.
+pub fn take_crate_override(args: MacroStream) -> syn::Result<(MacroStream, Option<Path>)> {
+    let segments = split_args(args.clone());
+    let Some(index) = segments.iter().position(|segment| is_crate_key(segment)) else {
+        return Ok((args, None));
+    };
+    let value = segments[index][2..]
+        .iter()
+        .cloned()
+        .collect::<MacroStream>();
+    let path = match syn::parse2::<LitStr>(value.clone()) {
+        Ok(lit) => lit.parse::<Path>()?,
+        Err(_) => syn::parse2::<Path>(value.clone()).map_err(|err| {
+            syn::Error::new(
+                err.span(),
+                format!("expected `{CRATE_KEY} = path` to `{CRATE_NAME}`: {err}"),
+            )
+        })?,
+    };
+    let rest = segments
+        .into_iter()
+        .enumerate()
+        .filter(|(ix, segment)| *ix != index && !segment.is_empty())
+        .map(|(_, segment)| quote! { #(#segment)* });
+    Ok((quote! { #(#rest),* }, Some(path)))
+}
+
+fn take_crate_override_from_attrs(
+    attrs: &mut [Attribute],
+    helper: &str,
+) -> syn::Result<Option<Path>> {
+    let mut found = None;
+    for attr in attrs {
+        if !attr.path().is_ident(helper) {
+            continue;
+        }
+        if let Meta::List(list) = &mut attr.meta {
+            let (tokens, path) = take_crate_override(std::mem::take(&mut list.tokens))?;
+            list.tokens = tokens;
+            found = path.or(found);
+        }
+    }
+    Ok(found)
+}
+
+fn split_args(args: MacroStream) -> Vec<Vec<TokenTree>> {
+    let mut segments = vec![Vec::new()];
+    for tree in args {
+        match &tree {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => segments.push(Vec::new()),
+            _ => segments.last_mut().expect("never empty").push(tree),
+        }
+    }
+    segments
+}
+
+fn is_crate_key(segment: &[TokenTree]) -> bool {
+    matches!(
+        segment,
+        [TokenTree::Ident(ident), TokenTree::Punct(eq), _, ..]
+            if ident == CRATE_KEY && eq.as_char() == '='
+    )
+}
+
+/// Replaces `::bevy_auto_plugin` path roots with `path`, forwarding `crate = path` to our attributes when `forward`
+pub fn rewrite_crate_root(tokens: MacroStream, path: &Path, forward: bool) -> MacroStream {
+    let trees = tokens.into_iter().collect::<Vec<_>>();
+    let mut out = Vec::<TokenTree>::with_capacity(trees.len());
+    let mut ix = 0;
+    while ix < trees.len() {
+        if is_crate_root(&trees, ix) {
+            out.extend(path.to_token_stream());
+            ix += 3;
+            continue;
+        }
+        match &trees[ix] {
+            TokenTree::Group(group) => {
+                let mut stream = rewrite_crate_root(group.stream(), path, forward);
+                let is_attribute =
+                    matches!(out.last(), Some(TokenTree::Punct(p)) if p.as_char() == '#');
+                if forward && is_attribute && group.delimiter() == Delimiter::Bracket {
+                    stream = forward_crate_path(stream, path);
+                }
+                let mut rewritten = Group::new(group.delimiter(), stream);
+                rewritten.set_span(group.span());
+                out.push(TokenTree::Group(rewritten));
+            }
+            tree => out.push(tree.clone()),
+        }
+        ix += 1;
+    }
+    out.into_iter().collect()
+}
+
+/// `::bevy_auto_plugin` at `ix` that starts a path, rather than continuing one like `game_core::bevy_auto_plugin`
+fn is_crate_root(trees: &[TokenTree], ix: usize) -> bool {
+    let [
+        TokenTree::Punct(first),
+        TokenTree::Punct(second),
+        TokenTree::Ident(ident),
+    ] = trees.get(ix..ix + 3).unwrap_or_default()
+    else {
+        return false;
+    };
+    if first.as_char() != ':' || first.spacing() != Spacing::Joint || second.as_char() != ':' {
+        return false;
+    }
+    if ident != CRATE_NAME {
+        return false;
+    }
+    let continues_path = match ix.checked_sub(1).map(|prev| &trees[prev]) {
+        Some(TokenTree::Ident(prev)) => {
+            matches!(
+                prev.to_string().as_str(),
+                "crate" | "self" | "super" | "Self"
+            ) || syn::parse2::<syn::Ident>(prev.to_token_stream()).is_ok()
+        }
+        Some(TokenTree::Punct(prev)) => prev.as_char() == '>',
+        _ => false,
+    };
+    !continues_path
+}
+
+/// Adds `crate = path` to the args of an attribute (the tokens inside `#[..]`) if it's one of ours and doesn't have one yet
+fn forward_crate_path(attr: MacroStream, path: &Path) -> MacroStream {
+    let mut trees = attr.into_iter().collect::<Vec<_>>();
+    let args_ix = trees
+        .iter()
+        .position(|tree| matches!(tree, TokenTree::Group(_) | TokenTree::Punct(_) if !is_path_punct(tree)))
+        .unwrap_or(trees.len());
+    let is_ours = matches!(
+        trees[..args_ix].last(),
+        Some(TokenTree::Ident(ident)) if FORWARD_TO.iter().any(|name| ident == name)
+    );
+    if !is_ours {
+        return trees.into_iter().collect();
+    }
+    let forwarded = quote! { #path };
+    let crate_key = format_ident!("{}", CRATE_KEY);
+    match trees.get(args_ix) {
+        None => {
+            let args = Group::new(Delimiter::Parenthesis, quote! { #crate_key = #forwarded });
+            trees.push(TokenTree::Group(args));
+        }
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let args = group.stream();
+            if split_args(args.clone())
+                .iter()
+                .any(|segment| is_crate_key(segment))
+            {
+                return trees.into_iter().collect();
+            }
+            let args = if args.is_empty() {
+                quote! { #crate_key = #forwarded }
+            } else {
+                let comma = Punct::new(',', Spacing::Alone);
+                quote! { #args #comma #crate_key = #forwarded }
+            };
+            let mut rewritten = Group::new(Delimiter::Parenthesis, args);
+            rewritten.set_span(group.span());
+            trees[args_ix] = TokenTree::Group(rewritten);
+        }
+        Some(_) => {}
+    }
+    trees.into_iter().collect()
+}
+
+fn is_path_punct(tree: &TokenTree) -> bool {
+    matches!(tree, TokenTree::Punct(punct) if punct.as_char() == ':')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[internal_test_proc_macro::xtest]
+    fn test_take_crate_override() {
+        let (args, path) =
+            take_crate_override(quote! { plugin = Foo, crate = ::game_core::bevy_auto_plugin })
+                .unwrap();
+        assert_eq!(args.to_string(), quote! { plugin = Foo }.to_string());
+        assert_eq!(
+            path.to_token_stream().to_string(),
+            quote! { ::game_core::bevy_auto_plugin }.to_string()
+        );
+
+        let (args, path) =
+            take_crate_override(quote! { crate = "game_core::bevy_auto_plugin", generics(u8) })
+                .unwrap();
+        assert_eq!(args.to_string(), quote! { generics(u8) }.to_string());
+        assert_eq!(
+            path.to_token_stream().to_string(),
+            quote! { game_core::bevy_auto_plugin }.to_string()
+        );
+
+        let (args, path) = take_crate_override(quote! { plugin = Foo }).unwrap();
+        assert_eq!(args.to_string(), quote! { plugin = Foo }.to_string());
+        assert!(path.is_none());
+
+        assert!(take_crate_override(quote! { crate = 1 }).is_err());
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_rewrite_crate_root() {
+        let path: Path = syn::parse_quote!(::game_core::bevy_auto_plugin);
+        let tokens = quote! {
+            use ::bevy_auto_plugin::prelude::*;
+            impl ::bevy_auto_plugin::__private::shared::Foo for Bar {}
+            type Kept = ::other::bevy_auto_plugin::Foo;
+        };
+        assert_eq!(
+            rewrite_crate_root(tokens, &path, false).to_string(),
+            quote! {
+                use ::game_core::bevy_auto_plugin::prelude::*;
+                impl ::game_core::bevy_auto_plugin::__private::shared::Foo for Bar {}
+                type Kept = ::other::bevy_auto_plugin::Foo;
+            }
+            .to_string()
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_rewrite_crate_root_forwards_override() {
+        let path: Path = syn::parse_quote!(crate::reexport::bevy_auto_plugin);
+        let tokens = quote! {
+            #[::bevy_auto_plugin::modes::global::prelude::auto_register_type(plugin = Foo)]
+            #[auto_name]
+            #[auto_init_resource(crate = ::bevy_auto_plugin)]
+            #[derive(Debug)]
+            struct Bar;
+        };
+        assert_eq!(
+            rewrite_crate_root(tokens, &path, true).to_string(),
+            quote! {
+                #[crate::reexport::bevy_auto_plugin::modes::global::prelude::auto_register_type(plugin = Foo, crate = crate::reexport::bevy_auto_plugin)]
+                #[auto_name(crate = crate::reexport::bevy_auto_plugin)]
+                #[auto_init_resource(crate = crate::reexport::bevy_auto_plugin)]
+                #[derive(Debug)]
+                struct Bar;
+            }
+            .to_string()
+        );
+    }
+}
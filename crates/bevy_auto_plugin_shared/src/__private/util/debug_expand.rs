@@ -0,0 +1,313 @@
+//! Dumps the input and output of a macro to files for debugging expansions,
+//! switched on with `BEVY_AUTO_PLUGIN_EXPAND_DIR=<dir>` or the `debug_expand` flag on a single attribute
+//!
+//! `proc_macro::tracked_env` is nightly only, so the env var is read untracked and cargo doesn't know
+//! expansions depend on it. Derives emit an `option_env!` of it instead (see [`track_expand_dir`]),
+//! which cargo does track: setting or changing it rebuilds every crate with a `#[derive(AutoPlugin)]`.
+//! Crates without one have to be touched to be dumped.
+
+use proc_macro2::{Span, TokenStream as MacroStream, TokenTree};
+use quote::quote;
+use std::path::{Path, PathBuf};
+use syn::{Attribute, DeriveInput, Item, Meta};
+
+/// Directory every expansion is written to, relative paths are resolved against the crate's manifest dir
+pub const EXPAND_DIR_ENV: &str = "BEVY_AUTO_PLUGIN_EXPAND_DIR";
+/// Attribute flag writing only that expansion, to [`EXPAND_DIR_ENV`] or `target/bevy_auto_plugin_expand`
+pub const DEBUG_EXPAND_FLAG: &str = "debug_expand";
+
+/// Runs an attribute macro, dumping the expansion when enabled
+pub fn expand_attribute(
+    kind: &str,
+    attr: MacroStream,
+    input: MacroStream,
+    expand: impl FnOnce(MacroStream, MacroStream) -> MacroStream,
+) -> MacroStream {
+    let (attr, flagged) = take_debug_expand_flag(attr);
+    let output = expand(attr.clone(), input.clone());
+    if let Some(dir) = expand_dir(flagged) {
+        let args = (!attr.is_empty()).then(|| format!("#[{kind}({attr})]"));
+        write_expansion(&dir, kind, args.as_deref(), &input, &output);
+    }
+    output
+}
+
+/// Runs a function-like macro, dumping the expansion when enabled
+pub fn expand_fn_like(
+    kind: &str,
+    input: MacroStream,
+    expand: impl FnOnce(MacroStream) -> MacroStream,
+) -> MacroStream {
+    let (input, flagged) = take_debug_expand_flag(input);
+    let output = expand(input.clone());
+    if let Some(dir) = expand_dir(flagged) {
+        write_expansion(&dir, kind, None, &input, &output);
+    }
+    output
+}
+
+/// Runs a derive macro, dumping the expansion when enabled, the flag goes in the `helper` attribute
+pub fn expand_derive(
+    kind: &str,
+    helper: &str,
+    input: MacroStream,
+    expand: impl FnOnce(MacroStream) -> MacroStream,
+) -> MacroStream {
+    let mut flagged = false;
+    let input = match syn::parse2::<DeriveInput>(input.clone()) {
+        Ok(mut derive_input) => {
+            flagged = take_debug_expand_flag_from_attrs(&mut derive_input.attrs, helper);
+            if flagged {
+                quote! { #derive_input }
+            } else {
+                input
+            }
+        }
+        Err(_) => input,
+    };
+    let mut output = expand(input.clone());
+    if let Some(dir) = expand_dir(flagged) {
+        write_expansion(&dir, kind, None, &input, &output);
+    }
+    output.extend(track_expand_dir());
+    output
+}
+
+/// Reads [`EXPAND_DIR_ENV`] through `option_env!`, so cargo rebuilds the crate when it changes
+pub fn track_expand_dir() -> MacroStream {
+    quote! {
+        const _: () = {
+            let _ = ::core::option_env!(#EXPAND_DIR_ENV);
+        };
+    }
+}
+
+/// Removes a top level `debug_expand` from comma separated args, returning whether it was there
+pub fn take_debug_expand_flag(args: MacroStream) -> (MacroStream, bool) {
+    let mut segments = vec![Vec::new()];
+    for tree in args.clone() {
+        match &tree {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => segments.push(Vec::new()),
+            _ => segments.last_mut().expect("never empty").push(tree),
+        }
+    }
+    let is_flag = |segment: &Vec<TokenTree>| match segment.as_slice() {
+        [TokenTree::Ident(ident)] => ident == DEBUG_EXPAND_FLAG,
+        _ => false,
+    };
+    if !segments.iter().any(is_flag) {
+        return (args, false);
+    }
+    let segments = segments
+        .into_iter()
+        .filter(|segment| !segment.is_empty() && !is_flag(segment))
+        .map(|segment| quote! { #(#segment)* });
+    (quote! { #(#segments),* }, true)
+}
+
+/// Removes `debug_expand` from every `helper` attribute, returning whether any had it
+pub fn take_debug_expand_flag_from_attrs(attrs: &mut [Attribute], helper: &str) -> bool {
+    let mut flagged = false;
+    for attr in attrs {
+        if !attr.path().is_ident(helper) {
+            continue;
+        }
+        if let Meta::List(list) = &mut attr.meta {
+            let (tokens, taken) = take_debug_expand_flag(std::mem::take(&mut list.tokens));
+            list.tokens = tokens;
+            flagged |= taken;
+        }
+    }
+    flagged
+}
+
+fn expand_dir(flagged: bool) -> Option<PathBuf> {
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+    let dir = match std::env::var_os(EXPAND_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => manifest_dir.join(dir),
+        _ if flagged => manifest_dir.join("target").join("bevy_auto_plugin_expand"),
+        _ => return None,
+    };
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_else(|_| "unknown".to_string());
+    Some(dir.join(crate_name))
+}
+
+/// Writes `<item>.<kind>.<location>.input.rs` and `<item>.<kind>.<location>.output.rs` to `dir`,
+/// see [`expansion_file_stem`].
+///
+/// Failing to write is ignored, this is only a debugging aid.
+pub fn write_expansion(
+    dir: &Path,
+    kind: &str,
+    args: Option<&str>,
+    input: &MacroStream,
+    output: &MacroStream,
+) {
+    let stem = expansion_file_stem(kind, input);
+    let header = args.map(|args| format!("// {args}\n")).unwrap_or_default();
+    let _ = std::fs::create_dir_all(dir);
+    let _ = std::fs::write(
+        dir.join(format!("{stem}.input.rs")),
+        format!("{header}{}", pretty(input)),
+    );
+    let _ = std::fs::write(
+        dir.join(format!("{stem}.output.rs")),
+        format!("{header}{}", pretty(output)),
+    );
+}
+
+/// `<item>.<kind>.<location>`, the location being the source file, line and column of `input`
+/// so same named items in different modules don't overwrite each other.
+///
+/// Items without a name (e.g. `auto_plugin_entry!`) are named after a hash of their input.
+fn expansion_file_stem(kind: &str, input: &MacroStream) -> String {
+    let span = input
+        .clone()
+        .into_iter()
+        .next()
+        .map_or_else(Span::call_site, |tree| tree.span());
+    let file = span
+        .file()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    let start = span.start();
+    format!(
+        "{}.{kind}.{file}_{}_{}",
+        expansion_name(input),
+        start.line,
+        start.column
+    )
+}
+
+fn expansion_name(input: &MacroStream) -> String {
+    let ident = match syn::parse2::<Item>(input.clone()) {
+        Ok(Item::Struct(item)) => Some(item.ident.to_string()),
+        Ok(Item::Enum(item)) => Some(item.ident.to_string()),
+        Ok(Item::Fn(item)) => Some(item.sig.ident.to_string()),
+        Ok(Item::Mod(item)) => Some(item.ident.to_string()),
+        Ok(Item::Type(item)) => Some(item.ident.to_string()),
+        Ok(Item::Impl(item)) => {
+            let self_ty = &item.self_ty;
+            Some(
+                quote!(#self_ty)
+                    .to_string()
+                    .chars()
+                    .filter(|c| c.is_alphanumeric() || *c == '_')
+                    .collect(),
+            )
+        }
+        _ => None,
+    };
+    ident.unwrap_or_else(|| {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        input.to_string().hash(&mut hasher);
+        format!("{:x}", hasher.finish())
+    })
+}
+
+/// Pretty prints `tokens` as a file, falling back to the raw tokens when they aren't one
+fn pretty(tokens: &MacroStream) -> String {
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => format!("{tokens}\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[internal_test_proc_macro::xtest]
+    fn test_take_debug_expand_flag() {
+        let (args, flagged) = take_debug_expand_flag(quote! { plugin = Foo, debug_expand });
+        assert!(flagged);
+        assert_eq!(args.to_string(), quote! { plugin = Foo }.to_string());
+
+        let (args, flagged) =
+            take_debug_expand_flag(quote! { debug_expand, plugin = Foo, generics(u8, bool) });
+        assert!(flagged);
+        assert_eq!(
+            args.to_string(),
+            quote! { plugin = Foo, generics(u8, bool) }.to_string()
+        );
+
+        let (args, flagged) = take_debug_expand_flag(quote! { plugin = Foo, debug_expand = true });
+        assert!(!flagged);
+        assert_eq!(
+            args.to_string(),
+            quote! { plugin = Foo, debug_expand = true }.to_string()
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_take_debug_expand_flag_from_attrs() {
+        let mut input = syn::parse2::<DeriveInput>(quote! {
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait, debug_expand)]
+            struct Foo;
+        })
+        .unwrap();
+        assert!(take_debug_expand_flag_from_attrs(
+            &mut input.attrs,
+            "auto_plugin"
+        ));
+        let attr = &input.attrs[1];
+        assert_eq!(
+            quote!(#attr).to_string(),
+            quote!(#[auto_plugin(impl_plugin_trait)]).to_string()
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_write_expansion() {
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_auto_plugin_expand_test_{}",
+            std::process::id()
+        ));
+        let input = syn::parse_str::<MacroStream>("struct Foo;").unwrap();
+        let output = quote! { struct Foo; impl Foo { fn foo() {} } };
+        write_expansion(
+            &dir,
+            "auto_register_type",
+            Some("#[auto_register_type(plugin = MyPlugin)]"),
+            &input,
+            &output,
+        );
+        let stem = expansion_file_stem("auto_register_type", &input);
+        let written = std::fs::read_to_string(dir.join(format!("{stem}.output.rs"))).unwrap();
+        assert_eq!(
+            written,
+            "// #[auto_register_type(plugin = MyPlugin)]\nstruct Foo;\nimpl Foo {\n    fn foo() {}\n}\n"
+        );
+        assert!(dir.join(format!("{stem}.input.rs")).exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_expansion_file_stem() {
+        let parse = |source: &str| syn::parse_str::<MacroStream>(source).unwrap();
+        let stem = expansion_file_stem("auto_register_type", &parse("\n  struct Foo;"));
+        assert!(stem.starts_with("Foo.auto_register_type."), "{stem}");
+        assert!(stem.ends_with("_2_2"), "{stem}");
+        // same item at another location, e.g. in another module
+        assert_ne!(
+            stem,
+            expansion_file_stem("auto_register_type", &parse("struct Foo;"))
+        );
+    }
+
+    #[internal_test_proc_macro::xtest]
+    fn test_track_expand_dir() {
+        assert_eq!(
+            track_expand_dir().to_string(),
+            quote! {
+                const _: () = {
+                    let _ = ::core::option_env!("BEVY_AUTO_PLUGIN_EXPAND_DIR");
+                };
+            }
+            .to_string()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use syn::{Attribute, Item};
+use syn::{Attribute, Generics, Item};
 
 pub enum TakeAndPutAttrsError {
     ItemDoesNotHaveAttrs,
@@ -39,3 +39,22 @@ impl ItemAttrsExt for Item {
         Ok(std::mem::replace(self.attrs_mut()?, attrs))
     }
 }
+
+pub trait ItemGenericsExt {
+    fn generics(&self) -> Option<&Generics>;
+}
+
+impl ItemGenericsExt for Item {
+    fn generics(&self) -> Option<&Generics> {
+        match self {
+            Item::Enum(i) => Some(&i.generics),
+            Item::Fn(i) => Some(&i.sig.generics),
+            Item::Impl(i) => Some(&i.generics),
+            Item::Struct(i) => Some(&i.generics),
+            Item::Trait(i) => Some(&i.generics),
+            Item::Type(i) => Some(&i.generics),
+            Item::Union(i) => Some(&i.generics),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 pub mod combo;
 pub mod concrete_path;
+pub mod crate_path;
 pub mod debug;
+pub mod debug_expand;
 pub mod env;
 pub mod extensions;
 pub mod fn_param;
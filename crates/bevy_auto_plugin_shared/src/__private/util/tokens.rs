@@ -1,5 +1,40 @@
-use proc_macro2::TokenStream as MacroStream;
+use proc_macro2::{Group, Span, TokenStream as MacroStream, TokenTree};
 
 pub fn to_compile_error(err: syn::Error) -> MacroStream {
     err.to_compile_error()
 }
+
+/// Gives every token the `span`, e.g. so errors in generated code point at the attribute
+pub fn respan(tokens: MacroStream, span: Span) -> MacroStream {
+    tokens
+        .into_iter()
+        .map(|mut tree| {
+            match &mut tree {
+                TokenTree::Group(group) => {
+                    let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                    respanned.set_span(span);
+                    *group = respanned;
+                }
+                tree => tree.set_span(span),
+            }
+            tree
+        })
+        .collect()
+}
+
+/// Prefixes the message of every error combined in `err`, keeping their spans
+pub fn prefix_errors(prefix: impl std::fmt::Display, err: syn::Error) -> syn::Error {
+    combine_errors(
+        err.into_iter()
+            .map(|err| syn::Error::new(err.span(), format!("{prefix}: {err}"))),
+    )
+    .expect("syn::Error always holds at least one error")
+}
+
+/// Combines `errors` into a single error reporting all of them, `None` if there are none
+pub fn combine_errors(errors: impl IntoIterator<Item = syn::Error>) -> Option<syn::Error> {
+    errors.into_iter().reduce(|mut combined, err| {
+        combined.combine(err);
+        combined
+    })
+}
@@ -0,0 +1,3 @@
+fn main() {
+    bevy_auto_plugin_build::generate().expect("failed to generate auto_plugins.rs");
+}
@@ -0,0 +1,34 @@
+//! App crate of the `build_script` end-to-end test, with entries bound to its own plugin and to
+//! [`internal_test_build_script_plugin::SharedPlugin`]
+
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use internal_test_build_script_plugin::{Hits, SharedPlugin};
+
+bevy_auto_plugin::modes::global::build_script::include_auto_plugins!();
+
+#[derive(AutoPlugin, Default)]
+#[auto_plugin(impl_plugin_trait)]
+pub struct GamePlugin;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = GamePlugin)]
+pub struct Score(pub u32);
+
+#[auto_system(plugin = GamePlugin, schedule = Update)]
+fn score(mut score: ResMut<Score>) {
+    score.0 += 1;
+}
+
+#[cfg(test)]
+#[auto_init_resource(plugin = GamePlugin)]
+#[derive(Resource, Debug, Default)]
+pub struct TestOnly;
+
+mod shared {
+    use super::*;
+
+    auto_plugin_entry!(plugin = SharedPlugin, |app| {
+        Hits::record(app, env!("CARGO_CRATE_NAME"));
+    });
+}
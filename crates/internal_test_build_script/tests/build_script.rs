@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use internal_test_build_script::{GamePlugin, Score};
+use internal_test_build_script_plugin::{Hits, SharedPlugin};
+
+#[internal_test_proc_macro::xtest]
+fn test_listed_entries() {
+    // `TestOnly` is behind `cfg(test)`, which isn't set for the lib of integration tests
+    assert_eq!(
+        internal_test_build_script::AUTO_PLUGIN_ENTRIES
+            .factories()
+            .len(),
+        3
+    );
+    assert_eq!(
+        internal_test_build_script_plugin::AUTO_PLUGIN_ENTRIES
+            .factories()
+            .len(),
+        1
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_entries_run_once() {
+    let mut app = App::new();
+    app.add_plugins((
+        internal_test_build_script::AUTO_PLUGIN_ENTRIES,
+        SharedPlugin,
+        GamePlugin,
+    ));
+    app.update();
+    // the generated lists and the linker sections hold the same entries
+    let mut hits = app.world().resource::<Hits>().0.clone();
+    hits.sort();
+    assert_eq!(
+        hits,
+        vec![
+            "internal_test_build_script",
+            "internal_test_build_script_plugin"
+        ]
+    );
+    assert_eq!(app.world().get_resource::<Score>(), Some(&Score(1)));
+}
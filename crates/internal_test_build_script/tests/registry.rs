@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+/// Only test in this binary, so no other test registers the generated entries first
+#[internal_test_proc_macro::xtest]
+fn test_entries_only_in_generated_lists() {
+    // nothing in the linker sections
+    assert_eq!(orphaned_entries(&App::new()), vec![]);
+
+    internal_test_build_script::AUTO_PLUGIN_ENTRIES.register();
+    assert_eq!(orphaned_entries(&App::new()).len(), 3);
+}
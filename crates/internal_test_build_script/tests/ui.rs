@@ -0,0 +1,11 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+/// Entries `bevy_auto_plugin_build` doesn't list, e.g. generated by a `macro_rules!`, fail to compile
+#[test]
+fn ui_tests() {
+    let entries_file = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ui/auto_plugins.in");
+    // SAFETY: set before trybuild spawns cargo, and no other test in this binary reads the environment
+    unsafe { std::env::set_var("BEVY_AUTO_PLUGIN_ENTRIES_FILE", entries_file) };
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
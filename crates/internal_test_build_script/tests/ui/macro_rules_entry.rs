@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+bevy_auto_plugin::modes::global::build_script::include_auto_plugins!();
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct MyPlugin;
+
+macro_rules! resource {
+    ($name:ident) => {
+        #[derive(Resource, Default)]
+        #[auto_init_resource(plugin = MyPlugin)]
+        struct $name;
+    };
+}
+
+resource!(Score);
+
+fn main() {}
@@ -0,0 +1,25 @@
+//! Plugin crate of the `build_script` end-to-end test, other crates bind entries to [`SharedPlugin`]
+
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+bevy_auto_plugin::modes::global::build_script::include_auto_plugins!();
+
+#[derive(AutoPlugin, Default)]
+#[auto_plugin(impl_plugin_trait)]
+pub struct SharedPlugin;
+
+/// Names of the crates whose `SharedPlugin` entries ran, once per entry
+#[derive(Resource, Debug, Default)]
+pub struct Hits(pub Vec<&'static str>);
+
+impl Hits {
+    pub fn record(app: &mut App, name: &'static str) {
+        app.init_resource::<Hits>();
+        app.world_mut().resource_mut::<Hits>().0.push(name);
+    }
+}
+
+auto_plugin_entry!(plugin = SharedPlugin, |app| {
+    Hits::record(app, env!("CARGO_CRATE_NAME"));
+});
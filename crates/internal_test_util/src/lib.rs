@@ -69,7 +69,6 @@ macro_rules! vec_spread {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     #[internal_test_proc_macro::xtest]
     fn test_vec_spread() {
         assert_eq!(vec_spread![1], vec![1]);
@@ -1,7 +1,18 @@
 /// Modes
 pub mod modes;
 
+/// Assertions for testing plugins
+#[cfg(feature = "testing")]
+pub mod testing;
+
+/// Bevy Remote Protocol methods for the global registry
+#[cfg(feature = "remote")]
+pub mod remote;
+
 #[doc(hidden)]
 pub mod __private {
     pub use bevy_auto_plugin_shared as shared;
+
+    #[cfg(feature = "mode_global")]
+    pub use bevy_auto_plugin_proc_macros::global_auto_preset_expand as auto_preset_expand;
 }
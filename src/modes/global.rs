@@ -2,6 +2,16 @@ pub mod prelude {
     #[doc(inline)]
     pub use bevy_auto_plugin_proc_macros::AutoPlugin;
 
+    #[doc(inline)]
+    pub use bevy_auto_plugin_shared::__private::modes::global::{
+        AuditAction, AutoPlugin, AutoPluginAuditPlugin, AutoPluginBuildStats,
+        AutoPluginBuildSummaryPlugin, AutoPluginEntryKind, AutoPluginFilter, OrphanedEntry,
+        SystemChain, SystemChainStep, orphaned_entries,
+    };
+
+    #[doc(inline)]
+    pub use bevy_auto_plugin_shared::__private::phase::PluginPhase;
+
     #[doc(inline)]
     pub use bevy_auto_plugin_proc_macros::global_auto_add_event as auto_add_event;
 
@@ -23,6 +33,18 @@ pub mod prelude {
     #[doc(inline)]
     pub use bevy_auto_plugin_proc_macros::global_auto_plugin as auto_plugin;
 
+    #[doc(inline)]
+    pub use bevy_auto_plugin_proc_macros::global_auto_plugin_entry as auto_plugin_entry;
+
+    #[doc(inline)]
+    pub use bevy_auto_plugin_proc_macros::global_auto_generics_set as auto_generics_set;
+
+    #[doc(inline)]
+    pub use bevy_auto_plugin_proc_macros::global_auto_preset as auto_preset;
+
+    #[doc(inline)]
+    pub use bevy_auto_plugin_proc_macros::global_auto_apply_preset as auto_apply_preset;
+
     #[doc(inline)]
     pub use bevy_auto_plugin_proc_macros::global_auto_register_state_type as auto_register_state_type;
 
@@ -53,3 +75,20 @@ pub mod prelude {
     #[doc(inline)]
     pub use bevy_auto_plugin_proc_macros::global_auto_bind_plugin as auto_bind_plugin;
 }
+
+/// Snapshots of the global registry for generating docs
+pub mod export {
+    #[doc(inline)]
+    pub use bevy_auto_plugin_shared::__private::modes::global::export::{
+        EntryExport, OrderingExport, PluginExport, RegistryExport, SourceExport, SubPluginExport,
+    };
+}
+
+/// Listing the entries from `build.rs` with `bevy_auto_plugin_build`, for targets where the linker sections get stripped
+pub mod build_script {
+    #[doc(inline)]
+    pub use bevy_auto_plugin_shared::__private::modes::global::build_script::BuildScriptEntries;
+
+    #[doc(inline)]
+    pub use bevy_auto_plugin_shared::include_auto_plugins;
+}
@@ -0,0 +1,76 @@
+//! [Bevy Remote Protocol](bevy_remote) methods for inspecting the auto plugins built in a running app.
+//!
+//! - `auto_plugin/list` - `[{ "name": .., "entries": <count> }]` for every built `AutoPlugin`
+//! - `auto_plugin/entries` - the built plugins with their entries (kind, target, phase, group, ordering and source),
+//!   pass `{ "plugin": "MyPlugin" }` to only return one plugin
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_auto_plugin::remote::AutoPluginRemotePlugin;
+//! use bevy_remote::RemotePlugin;
+//!
+//! App::new()
+//!     .add_plugins((RemotePlugin::default(), AutoPluginRemotePlugin))
+//!     .run();
+//! ```
+
+use bevy_auto_plugin_shared::__private::bevy_app::{App, Plugin};
+use bevy_auto_plugin_shared::__private::bevy_ecs::system::In;
+use bevy_auto_plugin_shared::__private::bevy_ecs::world::World;
+use bevy_auto_plugin_shared::__private::modes::global::RegistryExport;
+use bevy_remote::{BrpError, BrpResult, RemoteMethodSystemId, RemoteMethods, error_codes};
+use serde_json::Value;
+
+/// The `auto_plugin/list` method name
+pub const LIST_METHOD: &str = "auto_plugin/list";
+/// The `auto_plugin/entries` method name
+pub const ENTRIES_METHOD: &str = "auto_plugin/entries";
+
+/// Registers the `auto_plugin/*` methods with [`bevy_remote::RemotePlugin`], which must also be added
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AutoPluginRemotePlugin;
+
+impl Plugin for AutoPluginRemotePlugin {
+    fn build(&self, _app: &mut App) {}
+
+    // `RemotePlugin` inserts `RemoteMethods` in its build, which may run after ours
+    fn finish(&self, app: &mut App) {
+        let world = app.world_mut();
+        let list = world.register_system(process_list_request);
+        let entries = world.register_system(process_entries_request);
+        let Some(mut methods) = world.get_resource_mut::<RemoteMethods>() else {
+            panic!("`AutoPluginRemotePlugin` requires `bevy_remote::RemotePlugin`");
+        };
+        methods.insert(LIST_METHOD, RemoteMethodSystemId::Instant(list));
+        methods.insert(ENTRIES_METHOD, RemoteMethodSystemId::Instant(entries));
+    }
+}
+
+/// Handles `auto_plugin/list`
+pub fn process_list_request(In(_params): In<Option<Value>>, world: &World) -> BrpResult {
+    Ok(RegistryExport::from_world(world).list_value())
+}
+
+/// Handles `auto_plugin/entries`, optionally filtered by `{ "plugin": "MyPlugin" }`
+pub fn process_entries_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let plugin = match params.as_ref().and_then(|params| params.get("plugin")) {
+        None | Some(Value::Null) => None,
+        Some(Value::String(plugin)) => Some(plugin.as_str()),
+        Some(other) => {
+            return Err(invalid_params(format!(
+                "expected `plugin` to be a string, found `{other}`"
+            )));
+        }
+    };
+    RegistryExport::from_world(world)
+        .entries_value(plugin)
+        .map_err(invalid_params)
+}
+
+fn invalid_params(message: String) -> BrpError {
+    BrpError {
+        code: error_codes::INVALID_PARAMS,
+        message,
+        data: None,
+    }
+}
@@ -0,0 +1,128 @@
+//! Assertions over what a plugin registered in an [`App`].
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_auto_plugin::modes::global::prelude::*;
+//! use bevy_auto_plugin::testing::*;
+//!
+//! #[derive(AutoPlugin)]
+//! #[auto_plugin(impl_plugin_trait)]
+//! struct MyPlugin;
+//!
+//! #[auto_resource(plugin = MyPlugin, derive, register, reflect, init)]
+//! #[derive(Default)]
+//! struct Score(usize);
+//!
+//! #[auto_system(plugin = MyPlugin, schedule = Update)]
+//! fn add_score(mut score: ResMut<Score>) {
+//!     score.0 += 1;
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(MyPlugin);
+//! assert_registered::<Score>(&app);
+//! assert_resource_exists::<Score>(&app);
+//! assert_system_in_schedule(&app, Update, add_score);
+//! ```
+
+use bevy_auto_plugin_shared::__private::bevy_app::App;
+use bevy_auto_plugin_shared::__private::bevy_ecs::entity_disabling::Internal;
+use bevy_auto_plugin_shared::__private::bevy_ecs::event::Event;
+use bevy_auto_plugin_shared::__private::bevy_ecs::message::{Message, Messages};
+use bevy_auto_plugin_shared::__private::bevy_ecs::observer::Observer;
+use bevy_auto_plugin_shared::__private::bevy_ecs::query::Allow;
+use bevy_auto_plugin_shared::__private::bevy_ecs::reflect::AppTypeRegistry;
+use bevy_auto_plugin_shared::__private::bevy_ecs::resource::Resource;
+use bevy_auto_plugin_shared::__private::bevy_ecs::schedule::ScheduleLabel;
+use bevy_auto_plugin_shared::__private::bevy_ecs::system::{IntoSystem, System};
+use bevy_auto_plugin_shared::__private::bevy_state::state::{State, States};
+use std::any::{TypeId, type_name, type_name_of_val};
+
+/// Panics if `T` isn't in the [`AppTypeRegistry`]
+#[track_caller]
+pub fn assert_registered<T: 'static>(app: &App) {
+    let registered = app
+        .world()
+        .get_resource::<AppTypeRegistry>()
+        .is_some_and(|registry| registry.read().contains(TypeId::of::<T>()));
+    assert!(
+        registered,
+        "expected `{}` to be registered in the AppTypeRegistry",
+        type_name::<T>()
+    );
+}
+
+/// Panics if the resource `R` doesn't exist
+#[track_caller]
+pub fn assert_resource_exists<R: Resource>(app: &App) {
+    assert!(
+        app.world().contains_resource::<R>(),
+        "expected resource `{}` to exist",
+        type_name::<R>()
+    );
+}
+
+/// Panics if `system` wasn't added to the schedule `label`
+#[track_caller]
+pub fn assert_system_in_schedule<M>(
+    app: &App,
+    label: impl ScheduleLabel,
+    system: impl IntoSystem<(), (), M>,
+) {
+    let system_name = type_name_of_val(&system);
+    let system_type_id = System::type_id(&IntoSystem::into_system(system));
+    let Some(schedule) = app.get_schedule(label.intern()) else {
+        panic!("expected schedule `{label:?}` to exist");
+    };
+    let systems = &schedule.graph().systems;
+    assert!(
+        systems
+            .iter()
+            .any(|(_, scheduled, _)| System::type_id(&**scheduled) == system_type_id),
+        "expected system `{system_name}` in schedule `{label:?}` ({} other systems)",
+        systems.len(),
+    );
+}
+
+/// Panics if no observer watches for the event `E`.
+///
+/// Takes `&mut App` to flush the commands that finish spawning observers.
+#[track_caller]
+pub fn assert_observer_for<E: Event>(app: &mut App) {
+    app.world_mut().flush();
+    let world = app.world();
+    let has_observer = world.event_key::<E>().is_some_and(|event_key| {
+        // observers are `Internal` entities that queries skip by default
+        let query = world.try_query_filtered::<&Observer, Allow<Internal>>();
+        query.is_some_and(|mut query| {
+            query
+                .iter(world)
+                .any(|observer| observer.descriptor().event_keys().contains(&event_key))
+        })
+    });
+    assert!(
+        has_observer,
+        "expected an observer for event `{}`",
+        type_name::<E>()
+    );
+}
+
+/// Panics if the message `M` wasn't added with `add_message`
+#[track_caller]
+pub fn assert_message_registered<M: Message>(app: &App) {
+    assert!(
+        app.world().contains_resource::<Messages<M>>(),
+        "expected message `{}` to be registered",
+        type_name::<M>()
+    );
+}
+
+/// Panics if the state `S` wasn't initialized
+#[track_caller]
+pub fn assert_state_initialized<S: States>(app: &App) {
+    assert!(
+        app.world().contains_resource::<State<S>>(),
+        "expected state `{}` to be initialized",
+        type_name::<S>()
+    );
+}
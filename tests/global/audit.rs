@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Added;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Forgotten;
+
+#[derive(Resource, Debug, Default)]
+#[auto_init_resource(plugin = Added)]
+struct AddedRes;
+
+#[derive(Resource, Debug, Default)]
+#[auto_init_resource(plugin = Forgotten)]
+struct ForgottenRes;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(Added);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_orphaned_entries() {
+    let app = app();
+    let orphans = orphaned_entries(&app);
+    let forgotten = orphans
+        .iter()
+        .find(|orphan| {
+            orphan
+                .source
+                .is_some_and(|source| source.plugin == "Forgotten")
+        })
+        .expect("expected `Forgotten` entries to be orphaned");
+    assert_eq!(forgotten.kind, AutoPluginEntryKind::InitResource);
+    let source = forgotten.source.unwrap();
+    assert!(source.file.ends_with("audit.rs"), "{source}");
+    assert!(
+        !orphans
+            .iter()
+            .any(|orphan| orphan.source.is_some_and(|source| source.plugin == "Added"))
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+#[should_panic(expected = "`Forgotten` init_resource at tests")]
+fn test_audit_plugin_panics() {
+    let mut app = app();
+    app.add_plugins(AutoPluginAuditPlugin::panicking());
+    app.finish();
+    app.cleanup();
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use internal_test_util::create_minimal_app;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+struct FooRes(usize);
+
+#[derive(Resource, Debug, Default, PartialEq)]
+struct BarRes(usize);
+
+auto_plugin_entry!(plugin = Test, |app| {
+    app.insert_resource(FooRes(1));
+});
+
+auto_plugin_entry!(plugin = Test, |app: &mut App| {
+    app.init_resource::<BarRes>();
+});
+
+#[derive(Resource, Debug, Default, PartialEq)]
+struct Count(usize);
+
+// identical invocations in one module are separate entries
+auto_plugin_entry!(plugin = Test, |app| {
+    app.init_resource::<Count>();
+    app.world_mut().resource_mut::<Count>().0 += 1;
+});
+
+auto_plugin_entry!(plugin = Test, |app| {
+    app.init_resource::<Count>();
+    app.world_mut().resource_mut::<Count>().0 += 1;
+});
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(Test);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_auto_plugin_entry() {
+    let app = app();
+    assert_eq!(app.world().get_resource::<FooRes>(), Some(&FooRes(1)));
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_auto_plugin_entry_typed_param() {
+    let app = app();
+    assert_eq!(app.world().get_resource::<BarRes>(), Some(&BarRes(0)));
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_identical_entries() {
+    let app = app();
+    assert_eq!(app.world().get_resource::<Count>(), Some(&Count(2)));
+}
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use internal_test_util::{create_minimal_app, type_id_of};
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+auto_preset!(
+    /// Components deriving and reflecting `Debug` and `Default`, registered and named
+    game_component = auto_component(
+        derive(Debug, Default),
+        reflect(Debug, Default),
+        register,
+        auto_name
+    )
+);
+
+auto_preset!(counting_system = auto_system(schedule = Update));
+
+mod presets {
+    use super::*;
+
+    auto_preset!(game_resource = auto_resource(derive(Debug, Default), reflect, register, init));
+    // clippy doesn't see the macro is defined by a macro, without the `use` it can't be used by path
+    #[allow(clippy::single_component_path_imports)]
+    pub(crate) use game_resource;
+}
+
+#[auto_apply_preset(game_component, plugin = TestPlugin)]
+struct FooComponent;
+
+#[auto_apply_preset(game_component, plugin = TestPlugin, derive(Clone, PartialEq))]
+struct BarComponent(usize);
+
+#[auto_bind_plugin(plugin = TestPlugin)]
+#[auto_apply_preset(presets::game_resource, derive(PartialEq))]
+struct Counter(usize);
+
+#[auto_apply_preset(counting_system, plugin = TestPlugin)]
+fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[auto_apply_preset(counting_system, plugin = TestPlugin, schedule = PostUpdate)]
+fn count_after(mut counter: ResMut<Counter>) {
+    counter.0 += 10;
+}
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_preset_component() {
+    let mut app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<FooComponent>()));
+    assert!(type_registry.contains(type_id_of::<BarComponent>()));
+    drop(type_registry);
+    let entity = app.world_mut().spawn(FooComponent).id();
+    assert_eq!(
+        app.world().get::<Name>(entity),
+        Some(&Name::new("FooComponent"))
+    );
+    assert_eq!(format!("{:?}", FooComponent), "FooComponent");
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_preset_merges_use_site_args() {
+    // `derive(Clone, PartialEq)` adds to the preset's derives
+    assert_eq!(BarComponent(1).clone(), BarComponent(1));
+    assert_eq!(BarComponent::default(), BarComponent(0));
+    // `schedule = PostUpdate` replaces the preset's
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().get_resource::<Counter>(), Some(&Counter(11)));
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Resource, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+#[auto_register_type(plugin = Test)]
+#[auto_init_resource(plugin = Test)]
+struct Counter(usize);
+
+#[auto_system(plugin = Test, schedule = Update)]
+fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = Test, group = "debug")]
+struct DebugRes;
+
+#[derive(Event, Debug)]
+struct Ping;
+
+#[auto_observer(plugin = Test)]
+fn on_ping(_trigger: On<Ping>, mut counter: ResMut<Counter>) {
+    counter.0 += 10;
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_only_register_type() {
+    let mut app = App::new();
+    Test.build_filtered(
+        &mut app,
+        &AutoPluginFilter::new().with_kinds([AutoPluginEntryKind::RegisterType]),
+    );
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.get(std::any::TypeId::of::<Counter>()).is_some());
+    drop(registry);
+    assert_eq!(app.world().get_resource::<Counter>(), None);
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_without_observers_and_groups() {
+    let mut app = App::new();
+    Test::static_build_filtered(
+        &mut app,
+        &AutoPluginFilter::new()
+            .without_kinds([AutoPluginEntryKind::AddObserver])
+            .without_groups(["debug"]),
+    );
+    assert_eq!(app.world().get_resource::<DebugRes>(), None);
+    app.update();
+    app.world_mut().trigger(Ping);
+    assert_eq!(app.world().get_resource::<Counter>(), Some(&Counter(1)));
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_only_group() {
+    let mut app = App::new();
+    Test.build_filtered(&mut app, &AutoPluginFilter::new().with_groups(["debug"]));
+    assert_eq!(app.world().get_resource::<DebugRes>(), Some(&DebugRes));
+    assert_eq!(app.world().get_resource::<Counter>(), None);
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_disjoint_filters_then_full_build() {
+    let mut app = App::new();
+    Test.build_filtered(
+        &mut app,
+        &AutoPluginFilter::new().with_kinds([AutoPluginEntryKind::RegisterType]),
+    );
+    Test.build_filtered(&mut app, &AutoPluginFilter::new().with_groups(["debug"]));
+    assert_eq!(app.world().get_resource::<DebugRes>(), Some(&DebugRes));
+    assert_eq!(app.world().get_resource::<Counter>(), None);
+    // runs the entries neither filter matched, once
+    app.add_plugins(Test);
+    app.update();
+    assert_eq!(app.world().get_resource::<Counter>(), Some(&Counter(1)));
+    app.world_mut().trigger(Ping);
+    assert_eq!(app.world().get_resource::<Counter>(), Some(&Counter(11)));
+    // already built
+    Test.build_filtered(&mut app, &AutoPluginFilter::new());
+    app.update();
+    assert_eq!(app.world().get_resource::<Counter>(), Some(&Counter(12)));
+}
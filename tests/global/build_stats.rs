@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Resource, Debug, Default)]
+#[auto_init_resource(plugin = Test)]
+struct Counter(usize);
+
+#[auto_system(plugin = Test, schedule = Update)]
+fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_build_stats() {
+    let mut app = App::new();
+    app.add_plugins((AutoPluginBuildSummaryPlugin, Test));
+    app.finish();
+    app.cleanup();
+
+    let stats = app.world().resource::<AutoPluginBuildStats>();
+    let build = stats
+        .iter()
+        .find(|stats| stats.plugin.ends_with("::Test") && stats.phase == PluginPhase::Build)
+        .expect("expected build stats for `Test`");
+    assert_eq!(build.entries, 2);
+    assert_eq!(build.kinds.len(), 2);
+    assert!(
+        build
+            .kinds
+            .contains(&(AutoPluginEntryKind::InitResource, 1))
+    );
+    assert!(build.kinds.contains(&(AutoPluginEntryKind::AddSystem, 1)));
+    let (_, target, _) = build.slowest.expect("expected a slowest entry");
+    assert!(["Counter", "count"].contains(&target), "{target}");
+    assert!(stats.to_string().contains("1 init_resource"));
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_build_stats_disabled() {
+    let mut app = App::new();
+    app.add_plugins(Test);
+    app.update();
+
+    // only collected with `AutoPluginBuildSummaryPlugin`
+    assert!(!app.world().contains_resource::<AutoPluginBuildStats>());
+    assert_eq!(app.world().resource::<Counter>().0, 1);
+}
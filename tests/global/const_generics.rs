@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use internal_test_util::{create_minimal_app, type_id_of};
+use std::marker::PhantomData;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+const SIDE: usize = 4;
+
+#[auto_component(
+    plugin = TestPlugin,
+    generics(16, 16),
+    generics({ SIDE * 2 }, SIDE),
+    derive,
+    reflect,
+    register
+)]
+struct Grid<const W: usize, const H: usize>;
+
+#[auto_resource(plugin = TestPlugin, generics('static, u8), derive(Default), init)]
+struct Buffer<'a, T: Send + Sync>(PhantomData<&'a T>);
+
+#[auto_resource(plugin = TestPlugin, derive(Debug, Default, PartialEq), init)]
+struct Counter(usize);
+
+#[auto_system(plugin = TestPlugin, schedule = Update, generics(3))]
+fn count_by<const N: usize>(mut counter: ResMut<Counter>) {
+    counter.0 += N;
+}
+
+#[auto_register_type(plugin = TestPlugin, generics_product(T = [u8, bool], N = [1, 2]))]
+#[derive(Reflect)]
+struct Chunk<T, const N: usize>([T; N]);
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_const_generics() {
+    let mut app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<Grid<16, 16>>()));
+    assert!(type_registry.contains(type_id_of::<Grid<8, 4>>()));
+    assert!(type_registry.contains(type_id_of::<Chunk<u8, 1>>()));
+    assert!(type_registry.contains(type_id_of::<Chunk<bool, 2>>()));
+    drop(type_registry);
+    app.update();
+    assert_eq!(app.world().get_resource::<Counter>(), Some(&Counter(3)));
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_lifetime_generics() {
+    let app = app();
+    assert!(app.world().get_resource::<Buffer<'static, u8>>().is_some());
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use internal_test_util::create_minimal_app;
+use reexport::bevy_auto_plugin::modes::global::prelude::{
+    AutoPlugin, auto_apply_preset, auto_component, auto_init_resource, auto_plugin_entry,
+    auto_preset, auto_system,
+};
+
+/// Stands in for a crate like `game_core` re-exporting `bevy_auto_plugin` to its dependents
+mod reexport {
+    pub use bevy_auto_plugin;
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait, crate = crate::global::crate_path::reexport::bevy_auto_plugin)]
+struct TestPlugin;
+
+#[auto_component(
+    plugin = TestPlugin,
+    derive(Debug, Default, PartialEq),
+    reflect(Debug, Default),
+    register,
+    auto_name,
+    crate = crate::global::crate_path::reexport::bevy_auto_plugin,
+)]
+struct FooComponent;
+
+#[auto_init_resource(
+    plugin = TestPlugin,
+    crate = "crate::global::crate_path::reexport::bevy_auto_plugin"
+)]
+#[derive(Resource, Debug, Default, PartialEq)]
+struct Counter(usize);
+
+#[auto_system(
+    plugin = TestPlugin,
+    schedule = Update,
+    crate = crate::global::crate_path::reexport::bevy_auto_plugin,
+)]
+fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[derive(Resource, Debug, Default, PartialEq)]
+struct EntryRes(usize);
+
+auto_plugin_entry!(
+    plugin = TestPlugin,
+    crate = crate::global::crate_path::reexport::bevy_auto_plugin,
+    |app| {
+        app.insert_resource(EntryRes(1));
+    }
+);
+
+auto_preset!(
+    test_resource = auto_init_resource(plugin = TestPlugin),
+    crate = crate::global::crate_path::reexport::bevy_auto_plugin,
+);
+
+#[auto_apply_preset(test_resource)]
+#[derive(Resource, Debug, Default, PartialEq)]
+struct PresetRes(usize);
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_crate_path_override() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().get_resource::<Counter>(), Some(&Counter(1)));
+    assert_eq!(app.world().get_resource::<EntryRes>(), Some(&EntryRes(1)));
+    assert_eq!(app.world().get_resource::<PresetRes>(), Some(&PresetRes(0)));
+    let type_registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(type_registry.contains(std::any::TypeId::of::<FooComponent>()));
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait, debug_expand)]
+struct DebugExpandPlugin;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = DebugExpandPlugin, debug_expand)]
+struct DebugExpandResource(usize);
+
+#[internal_test_proc_macro::xtest]
+fn test_debug_expand_flag_is_stripped() {
+    let mut app = App::new();
+    app.add_plugins(DebugExpandPlugin);
+    assert_eq!(
+        app.world().get_resource::<DebugExpandResource>(),
+        Some(&DebugExpandResource(0))
+    );
+}
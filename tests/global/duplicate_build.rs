@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait, is_unique = false)]
+struct NotUnique;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = Test)]
+#[auto_init_resource(plugin = NotUnique)]
+struct Counter(usize);
+
+#[auto_system(plugin = Test, schedule = Update)]
+fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[auto_system(plugin = NotUnique, schedule = Update)]
+fn count_not_unique(mut counter: ResMut<Counter>) {
+    counter.0 += 10;
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_static_build_after_plugin() {
+    let mut app = App::new();
+    app.add_plugins(Test);
+    <Test as AutoPlugin>::static_build(&mut app);
+    app.update();
+    assert_eq!(app.world().resource::<Counter>(), &Counter(1));
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_not_unique() {
+    assert!(!Plugin::is_unique(&NotUnique));
+    let mut app = App::new();
+    app.add_plugins(NotUnique);
+    app.add_plugins(NotUnique);
+    app.update();
+    assert_eq!(app.world().resource::<Counter>(), &Counter(10));
+}
@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::export::RegistryExport;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Parent;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Child;
+
+auto_plugin_entry!(plugin = Parent, |app| {
+    app.add_plugins(Child);
+});
+
+#[auto_system(plugin = Child, schedule = Update)]
+fn first() {}
+
+#[auto_system(plugin = Child, schedule = Update, config(after = first))]
+fn second() {}
+
+fn child_name() -> &'static str {
+    std::any::type_name::<Child>()
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_registry_export() {
+    let mut app = App::new();
+    app.add_plugins(Parent);
+    let export = RegistryExport::from_registry().with_app(&app);
+
+    let child = export
+        .plugins
+        .iter()
+        .find(|plugin| plugin.name == child_name())
+        .expect("expected `Child` in the export");
+    let targets = child
+        .entries
+        .iter()
+        .map(|entry| entry.target)
+        .collect::<Vec<_>>();
+    assert_eq!(targets, vec![Some("first"), Some("second")]);
+    let second = &child.entries[1];
+    assert_eq!(second.kind, "add_system");
+    assert_eq!(
+        second
+            .ordering
+            .iter()
+            .map(|ordering| (ordering.arg, ordering.path))
+            .collect::<Vec<_>>(),
+        vec![("schedule", "Update"), ("after", "first")]
+    );
+    assert!(second.source.as_ref().unwrap().file.ends_with("export.rs"));
+    assert_eq!(second.source.as_ref().unwrap().module, module_path!());
+
+    assert!(export.sub_plugins.iter().any(|edge| {
+        edge.parent == std::any::type_name::<Parent>() && edge.child == child_name()
+    }));
+
+    let dot = export.to_dot();
+    let module = module_path!();
+    assert!(dot.contains(&format!(
+        r#""system:{module}::first" -> "system:{module}::second" [style=dashed, label="after"];"#
+    )));
+    assert!(dot.contains(r#"[style=bold, label="add_plugins"];"#));
+
+    let json: serde_json::Value = serde_json::from_str(&export.to_json()).unwrap();
+    assert!(
+        json["plugins"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|plugin| plugin["name"] == child_name())
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_built_plugins_export() {
+    let mut app = App::new();
+    app.add_plugins(Parent);
+    let export = RegistryExport::from_world(app.world());
+
+    let names = export
+        .plugins
+        .iter()
+        .map(|plugin| plugin.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec![std::any::type_name::<Parent>(), child_name()]);
+    assert_eq!(
+        export.list_value(),
+        serde_json::json!([
+            { "name": std::any::type_name::<Parent>(), "entries": 1 },
+            { "name": child_name(), "entries": 2 },
+        ])
+    );
+    let entries = export.entries_value(Some("Child")).unwrap();
+    assert_eq!(entries[0]["entries"][1]["target"], "second");
+    assert_eq!(
+        export.entries_value(Some("Missing")).unwrap_err(),
+        "no auto plugin named `Missing` was built"
+    );
+}
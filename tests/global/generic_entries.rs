@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use std::marker::PhantomData;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(
+    impl_generic_plugin_trait,
+    impl_generic_auto_plugin_trait,
+    generics(Sword),
+    generics(Shield)
+)]
+struct InventoryPlugin<T: Send + Sync + 'static>(PhantomData<T>);
+
+impl<T: Send + Sync + 'static> Default for InventoryPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Sword;
+
+#[derive(Debug, Default, PartialEq)]
+struct Shield;
+
+#[derive(Resource, Debug, PartialEq)]
+#[auto_init_resource(plugin = InventoryPlugin::<T>, generics(T))]
+struct Inventory<T: Send + Sync + 'static>(Vec<T>);
+
+impl<T: Send + Sync + 'static> Default for Inventory<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+#[auto_system(plugin = InventoryPlugin::<T>, generics(T), schedule = Update)]
+fn add_item<T: Default + Send + Sync + 'static>(mut inventory: ResMut<Inventory<T>>) {
+    inventory.0.push(T::default());
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_generic_entries() {
+    let mut app = App::new();
+    app.add_plugins(InventoryPlugin::<Sword>::default());
+    app.update();
+    assert_eq!(
+        app.world().get_resource::<Inventory<Sword>>(),
+        Some(&Inventory(vec![Sword]))
+    );
+    assert_eq!(app.world().get_resource::<Inventory<Shield>>(), None);
+
+    app.add_plugins(InventoryPlugin::<Shield>::default());
+    app.update();
+    assert_eq!(
+        app.world().get_resource::<Inventory<Sword>>(),
+        Some(&Inventory(vec![Sword, Sword]))
+    );
+    assert_eq!(
+        app.world().get_resource::<Inventory<Shield>>(),
+        Some(&Inventory(vec![Shield]))
+    );
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use internal_test_util::{create_minimal_app, type_id_of};
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+auto_generics_set!(
+    /// Unsigned integers
+    Unsigned = [u8, u16, u32]
+);
+
+mod sets {
+    use super::*;
+
+    auto_generics_set!(Flags = [bool, ()]);
+    // clippy doesn't see the macro is defined by a macro, without the `use` it can't be used by path
+    #[allow(clippy::single_component_path_imports)]
+    pub(crate) use Flags;
+}
+
+#[derive(Reflect)]
+#[auto_register_type(plugin = TestPlugin, generics_set = Unsigned)]
+struct FooValue<T>(T);
+
+#[derive(Reflect)]
+#[auto_register_type(plugin = TestPlugin, generics(f32), generics_set = sets::Flags)]
+struct BarValue<T>(T);
+
+#[auto_component(
+    plugin = TestPlugin,
+    generics_product(B = [bool, ()], A = [u8, f32]),
+    derive,
+    reflect,
+    register
+)]
+struct FooPair<A: Reflect + TypePath, B: Reflect + TypePath>(A, B);
+
+#[auto_resource(plugin = TestPlugin, generics_set = Unsigned, derive(Default), init)]
+struct Count<T: Default + Send + Sync + 'static>(T);
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_generics_set() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<FooValue<u8>>()));
+    assert!(type_registry.contains(type_id_of::<FooValue<u16>>()));
+    assert!(type_registry.contains(type_id_of::<FooValue<u32>>()));
+    assert!(!type_registry.contains(type_id_of::<FooValue<u64>>()));
+    assert!(type_registry.contains(type_id_of::<BarValue<f32>>()));
+    assert!(type_registry.contains(type_id_of::<BarValue<bool>>()));
+    assert!(type_registry.contains(type_id_of::<BarValue<()>>()));
+    drop(type_registry);
+    assert!(app.world().get_resource::<Count<u8>>().is_some());
+    assert!(app.world().get_resource::<Count<u32>>().is_some());
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_generics_product() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<FooPair<u8, bool>>()));
+    assert!(type_registry.contains(type_id_of::<FooPair<u8, ()>>()));
+    assert!(type_registry.contains(type_id_of::<FooPair<f32, bool>>()));
+    assert!(type_registry.contains(type_id_of::<FooPair<f32, ()>>()));
+}
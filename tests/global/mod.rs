@@ -1,10 +1,30 @@
+mod audit;
 mod auto_bind_plugin;
 mod auto_plugin;
+mod auto_plugin_entry;
 mod auto_plugin_with_generics;
+mod auto_preset;
 mod bare_fn;
 mod bare_fn_default_app_param;
+mod build_filtered;
+mod build_stats;
+mod const_generics;
+mod crate_path;
+mod debug_expand;
+mod duplicate_build;
+mod export;
+mod generic_entries;
+mod generics_set;
+mod plugin_config;
+mod plugin_phase;
+#[cfg(feature = "remote")]
+mod remote;
 mod self_impl_plugin;
 mod self_impl_plugin_default_app_param;
 mod self_impl_plugin_with_generics;
+mod sub_app;
+mod system_adapters;
+mod system_chain;
+mod testing;
 #[cfg(not(wasm))]
 mod ui_tests;
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin, Resource, Clone, Debug, PartialEq)]
+#[auto_plugin(impl_plugin_trait, insert_self_as_resource)]
+struct Test {
+    tick_rate: f32,
+}
+
+#[derive(Resource, Debug, PartialEq)]
+#[auto_insert_resource(plugin = Test, from_plugin = |p: &Test| TickRate(p.tick_rate))]
+struct TickRate(f32);
+
+#[derive(Resource, Debug, PartialEq)]
+#[auto_insert_resource(plugin = Test, phase = finish, from_plugin = tick_rate_doubled)]
+struct TickRateDoubled(f32);
+
+fn tick_rate_doubled(plugin: &Test) -> TickRateDoubled {
+    TickRateDoubled(plugin.tick_rate * 2.0)
+}
+
+#[derive(Resource, Debug, PartialEq)]
+struct FromEntry(f32);
+
+auto_plugin_entry!(plugin = Test, |app, plugin| {
+    app.insert_resource(FromEntry(plugin.tick_rate));
+});
+
+#[internal_test_proc_macro::xtest]
+fn test_from_plugin() {
+    let mut app = App::new();
+    app.add_plugins(Test { tick_rate: 2.5 });
+    assert_eq!(app.world().get_resource::<TickRate>(), Some(&TickRate(2.5)));
+    assert_eq!(
+        app.world().get_resource::<FromEntry>(),
+        Some(&FromEntry(2.5))
+    );
+    assert_eq!(app.world().get_resource::<TickRateDoubled>(), None);
+    app.finish();
+    assert_eq!(
+        app.world().get_resource::<TickRateDoubled>(),
+        Some(&TickRateDoubled(5.0))
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_insert_self_as_resource() {
+    let mut app = App::new();
+    app.add_plugins(Test { tick_rate: 2.5 });
+    assert_eq!(
+        app.world().get_resource::<Test>(),
+        Some(&Test { tick_rate: 2.5 })
+    );
+}
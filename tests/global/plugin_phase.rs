@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = Test)]
+struct BuildRes(usize);
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_insert_resource(plugin = Test, phase = finish, resource(FinishRes(1)))]
+struct FinishRes(usize);
+
+#[auto_resource(plugin = Test, phase = finish, derive(Debug, Default, PartialEq), init)]
+struct FinishRes2(usize);
+
+#[auto_bind_plugin(plugin = Test, phase = cleanup)]
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource]
+#[auto_insert_resource(phase = build, resource(CleanupRes(1)))]
+struct CleanupRes(usize);
+
+auto_plugin_entry!(plugin = Test, phase = cleanup, |app| {
+    app.world_mut().resource_mut::<FinishRes>().0 += 1;
+});
+
+#[derive(AutoPlugin)]
+struct SelfImpl;
+
+impl Plugin for SelfImpl {
+    #[auto_plugin]
+    fn build(&self, _app: &mut App) {}
+
+    #[auto_plugin]
+    fn finish(&self, _app: &mut App) {}
+}
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = SelfImpl, phase = finish)]
+struct SelfImplFinishRes(usize);
+
+#[derive(AutoPlugin)]
+struct BareFn;
+
+#[auto_plugin(plugin = BareFn)]
+fn bare_fn_plugin(_app: &mut App) {}
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = BareFn, phase = cleanup)]
+struct BareFnCleanupRes(usize);
+
+#[internal_test_proc_macro::xtest]
+fn test_phases() {
+    let mut app = App::new();
+    app.add_plugins(Test);
+    assert_eq!(app.world().get_resource::<BuildRes>(), Some(&BuildRes(0)));
+    assert_eq!(app.world().get_resource::<FinishRes>(), None);
+    assert_eq!(app.world().get_resource::<FinishRes2>(), None);
+    // `phase = build` on the attribute wins over the bound `phase = cleanup`
+    assert_eq!(
+        app.world().get_resource::<CleanupRes>(),
+        Some(&CleanupRes(1))
+    );
+
+    app.finish();
+    assert_eq!(app.world().get_resource::<FinishRes>(), Some(&FinishRes(1)));
+    assert_eq!(
+        app.world().get_resource::<FinishRes2>(),
+        Some(&FinishRes2(0))
+    );
+
+    app.cleanup();
+    assert_eq!(app.world().get_resource::<FinishRes>(), Some(&FinishRes(2)));
+    // already inserted during build so init is a no-op
+    assert_eq!(
+        app.world().get_resource::<CleanupRes>(),
+        Some(&CleanupRes(1))
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_self_impl_finish() {
+    let mut app = App::new();
+    app.add_plugins(SelfImpl);
+    assert_eq!(app.world().get_resource::<SelfImplFinishRes>(), None);
+    app.finish();
+    assert_eq!(
+        app.world().get_resource::<SelfImplFinishRes>(),
+        Some(&SelfImplFinishRes(0))
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_bare_fn_cleanup() {
+    let mut app = App::new();
+    app.add_plugins(BareFn);
+    app.finish();
+    assert_eq!(app.world().get_resource::<BareFnCleanupRes>(), None);
+    app.cleanup();
+    assert_eq!(
+        app.world().get_resource::<BareFnCleanupRes>(),
+        Some(&BareFnCleanupRes(0))
+    );
+}
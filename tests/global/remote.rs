@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use bevy_auto_plugin::remote::{AutoPluginRemotePlugin, ENTRIES_METHOD, LIST_METHOD};
+use bevy_remote::{BrpResult, RemoteMethodSystemId, RemoteMethods, RemotePlugin};
+use serde_json::{Value, json};
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Component, Reflect, Debug, Default)]
+#[auto_register_type(plugin = Test)]
+struct Health;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((Test, RemotePlugin::default(), AutoPluginRemotePlugin));
+    app.finish();
+    app.cleanup();
+    app
+}
+
+fn call(app: &mut App, method: &str, params: Option<Value>) -> BrpResult {
+    let Some(&RemoteMethodSystemId::Instant(system)) =
+        app.world().resource::<RemoteMethods>().get(method)
+    else {
+        panic!("method `{method}` not registered");
+    };
+    app.world_mut().run_system_with(system, params).unwrap()
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_list() {
+    let mut app = app();
+    let list = call(&mut app, LIST_METHOD, None).unwrap();
+    assert_eq!(
+        list,
+        json!([{ "name": std::any::type_name::<Test>(), "entries": 1 }])
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_entries() {
+    let mut app = app();
+    let entries = call(&mut app, ENTRIES_METHOD, Some(json!({ "plugin": "Test" }))).unwrap();
+    assert_eq!(entries[0]["entries"][0]["kind"], "register_type");
+    assert_eq!(entries[0]["entries"][0]["target"], "Health");
+
+    let err = call(
+        &mut app,
+        ENTRIES_METHOD,
+        Some(json!({ "plugin": "Missing" })),
+    )
+    .unwrap_err();
+    assert_eq!(err.message, "no auto plugin named `Missing` was built");
+}
@@ -0,0 +1,76 @@
+use bevy::app::AppLabel;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct SimApp;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = Test, sub_app = SimApp)]
+struct SimRes(usize);
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = Test)]
+struct MainRes(usize);
+
+#[auto_bind_plugin(plugin = Test, sub_app = SimApp)]
+#[derive(Component, Debug, Default, PartialEq)]
+#[auto_name]
+struct SimComponent;
+
+#[auto_system(plugin = Test, sub_app = SimApp, schedule = Update)]
+fn sim_system(mut res: ResMut<SimRes>) {
+    res.0 += 1;
+}
+
+#[derive(Event, Debug)]
+struct SimEvent;
+
+#[auto_observer(plugin = Test, sub_app = SimApp)]
+fn sim_observer(_trigger: On<SimEvent>, mut res: ResMut<SimRes>) {
+    res.0 += 10;
+}
+
+fn app_with_sim() -> App {
+    let mut app = App::new();
+    let mut sim = SubApp::new();
+    sim.init_schedule(Update);
+    sim.update_schedule = Some(Update.intern());
+    app.insert_sub_app(SimApp, sim);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_sub_app() {
+    let mut app = app_with_sim();
+    app.add_plugins(Test);
+    assert_eq!(app.world().get_resource::<SimRes>(), None);
+    assert_eq!(app.world().get_resource::<MainRes>(), Some(&MainRes(0)));
+
+    let sim = app.sub_app_mut(SimApp);
+    assert_eq!(sim.world().get_resource::<SimRes>(), Some(&SimRes(0)));
+    assert_eq!(sim.world().get_resource::<MainRes>(), None);
+
+    sim.update();
+    assert_eq!(sim.world().get_resource::<SimRes>(), Some(&SimRes(1)));
+
+    sim.world_mut().trigger(SimEvent);
+    assert_eq!(sim.world().get_resource::<SimRes>(), Some(&SimRes(11)));
+
+    let entity = sim.world_mut().spawn(SimComponent).id();
+    assert_eq!(
+        sim.world().get::<Name>(entity),
+        Some(&Name::new("SimComponent"))
+    );
+}
+
+#[internal_test_proc_macro::xtest]
+#[should_panic(expected = "sub app `SimApp` not found while building `Test`")]
+fn test_missing_sub_app() {
+    App::new().add_plugins(Test);
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use internal_test_util::create_minimal_app;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_resource(plugin = TestPlugin, derive(Debug, Default, PartialEq), init)]
+struct Log(Vec<String>);
+
+fn log_output(In(output): In<usize>, mut log: ResMut<Log>) {
+    log.0.push(format!("piped {output}"));
+}
+
+// systems may run on any thread, and every test's app records into it
+static ERRORS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+fn record_error(err: BevyError) {
+    // the message is followed by a backtrace when one is captured
+    let message = err
+        .to_string()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    ERRORS.lock().unwrap().push(message);
+}
+
+#[auto_system(plugin = TestPlugin, schedule = Update, pipe = log_output)]
+fn produce() -> usize {
+    1
+}
+
+fn always() -> bool {
+    true
+}
+
+#[auto_system(
+    plugin = TestPlugin,
+    schedule = Update,
+    generics(u8),
+    generics(u16),
+    map = |size: usize| size * 2,
+    pipe = log_output,
+    config(run_if = always)
+)]
+fn size<T>() -> usize {
+    std::mem::size_of::<T>()
+}
+
+#[auto_add_system(plugin = TestPlugin, schedule = Update, on_error = record_error)]
+fn fails() -> Result {
+    Err("failed".into())
+}
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_pipe_and_map() {
+    let mut app = app();
+    app.update();
+    let mut log = app.world().resource::<Log>().0.clone();
+    log.sort();
+    assert_eq!(log, vec!["piped 1", "piped 2", "piped 4"]);
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_on_error() {
+    let mut app = app();
+    app.update();
+    let errors = ERRORS.lock().unwrap();
+    assert!(!errors.is_empty());
+    assert!(errors.iter().all(|message| message == "failed"));
+}
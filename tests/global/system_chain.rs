@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use internal_test_util::create_minimal_app;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_resource(plugin = TestPlugin, derive(Debug, Default, PartialEq), init)]
+struct Order(Vec<&'static str>);
+
+#[auto_resource(plugin = TestPlugin, derive(Debug, Default, PartialEq), init)]
+struct Paused(bool);
+
+fn not_paused(paused: Res<Paused>) -> bool {
+    !paused.0
+}
+
+// declared out of order, as if spread across files
+#[auto_system(plugin = TestPlugin, schedule = Update, chain = "movement", chain_index = 2)]
+fn collide(mut order: ResMut<Order>) {
+    order.0.push("collide");
+}
+
+#[auto_system(
+    plugin = TestPlugin,
+    schedule = Update,
+    chain = "movement",
+    chain_index = 0,
+    chain_config(run_if = not_paused)
+)]
+fn input(mut order: ResMut<Order>) {
+    order.0.push("input");
+}
+
+#[auto_system(
+    plugin = TestPlugin,
+    schedule = Update,
+    generics(u8),
+    generics(u16),
+    chain = "movement",
+    chain_index = 1
+)]
+fn integrate<T>(mut order: ResMut<Order>) {
+    order.0.push(std::any::type_name::<T>());
+}
+
+#[auto_add_system(plugin = TestPlugin, schedule = Update, chain = "movement", chain_index = 3)]
+fn commands(mut commands: Commands) {
+    commands.spawn(Name::new("spawned"));
+}
+
+#[auto_add_system(plugin = TestPlugin, schedule = Update, chain = "movement", chain_index = 4)]
+fn count(q: Query<&Name>, mut order: ResMut<Order>) {
+    order.0.push(if q.is_empty() { "empty" } else { "spawned" });
+}
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_chain_order() {
+    let mut app = app();
+    app.update();
+    let order = &app.world().resource::<Order>().0;
+    assert_eq!(order.len(), 5);
+    assert_eq!(order[0], "input");
+    // the generic instances share an index and may run in either order
+    let mut integrated = order[1..3].to_vec();
+    integrated.sort();
+    assert_eq!(integrated, vec!["u16", "u8"]);
+    assert_eq!(order[3], "collide");
+    // commands applied before the next index like `.chain()`
+    assert_eq!(order[4], "spawned");
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_chain_config() {
+    let mut app = app();
+    app.world_mut().resource_mut::<Paused>().0 = true;
+    app.update();
+    assert_eq!(app.world().resource::<Order>().0, Vec::<&str>::new());
+}
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use bevy_auto_plugin::testing::*;
+use bevy_state::app::StatesPlugin;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_resource(plugin = Test, derive, register, reflect, init)]
+#[derive(Default)]
+struct FooRes(usize);
+
+#[derive(Message)]
+#[auto_add_event(plugin = Test)]
+struct FooMessage;
+
+#[derive(Event)]
+struct FooEvent;
+
+#[auto_observer(plugin = Test)]
+fn foo_observer(_trigger: On<FooEvent>) {}
+
+#[auto_states(plugin = Test, derive, init)]
+enum FooState {
+    #[default]
+    Start,
+}
+
+#[auto_system(plugin = Test, schedule = Update)]
+fn foo_system() {}
+
+fn other_system() {}
+
+#[derive(Resource)]
+struct Unused;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(Test);
+    app
+}
+
+#[internal_test_proc_macro::xtest]
+fn test_assertions() {
+    let mut app = app();
+    assert_registered::<FooRes>(&app);
+    assert_resource_exists::<FooRes>(&app);
+    assert_message_registered::<FooMessage>(&app);
+    assert_observer_for::<FooEvent>(&mut app);
+    assert_state_initialized::<FooState>(&app);
+    assert_system_in_schedule(&app, Update, foo_system);
+}
+
+#[internal_test_proc_macro::xtest]
+#[should_panic(
+    expected = "expected system `mod::global::testing::other_system` in schedule `Update`"
+)]
+fn test_assert_system_in_schedule_missing() {
+    assert_system_in_schedule(&app(), Update, other_system);
+}
+
+#[internal_test_proc_macro::xtest]
+#[should_panic(expected = "expected resource")]
+fn test_assert_resource_exists_missing() {
+    assert_resource_exists::<Unused>(&app());
+}
+
+#[internal_test_proc_macro::xtest]
+#[should_panic(expected = "expected an observer for event")]
+fn test_assert_observer_for_missing() {
+    assert_observer_for::<bevy::ecs::lifecycle::Add>(&mut app());
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_add_observer(plugin = TestPlugin)]
+fn not_an_observer(_: Res<Time>) {}
+
+// dummy main
+fn main() {}
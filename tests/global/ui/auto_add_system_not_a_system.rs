@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+struct NotAParam;
+
+#[auto_add_system(plugin = TestPlugin, schedule = Update)]
+fn not_a_system(_: NotAParam) {}
+
+// dummy main
+fn main() {}
@@ -0,0 +1,11 @@
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_apply_preset(plugin = TestPlugin, reflect = true)]
+struct Foo;
+
+// dummy main
+fn main() {}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_bind_plugin(plugn = TestPlugin)]
+#[auto_register_type]
+#[derive(Reflect)]
+struct Foo;
+
+// dummy main
+fn main() {
+    // still defined despite the error above
+    let _ = Foo;
+}
@@ -0,0 +1,13 @@
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_bind_plugin(plugin = TestPlugin)]
+const COUNT: u32 = 1;
+
+// dummy main
+fn main() {
+    let _ = COUNT;
+}
@@ -0,0 +1,14 @@
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_component(plugin = TestPlugin, regiser)]
+struct Foo;
+
+// `Foo` is still emitted, so only the attribute error is reported
+fn uses_foo(_: Foo) {}
+
+// dummy main
+fn main() {}
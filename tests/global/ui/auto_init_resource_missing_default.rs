@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_init_resource(plugin = TestPlugin)]
+#[derive(Resource)]
+struct NoDefault(u32);
+
+// dummy main
+fn main() {}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+struct TestPlugin(u32);
+
+#[auto_plugin(plugin = TestPlugin)]
+fn plugin(_app: &mut App) {}
+
+#[derive(Resource)]
+#[auto_insert_resource(plugin = TestPlugin, from_plugin = |p: &TestPlugin| FromPlugin(p.0))]
+struct FromPlugin(u32);
+
+auto_plugin_entry!(plugin = TestPlugin, |app, plugin| {
+    app.insert_resource(FromPlugin(plugin.0));
+});
+
+// dummy main
+fn main() {}
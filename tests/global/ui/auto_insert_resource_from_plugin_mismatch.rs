@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin(u32);
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct OtherPlugin(u32);
+
+#[derive(Resource)]
+#[auto_insert_resource(plugin = TestPlugin, from_plugin = |p: &OtherPlugin| FromPlugin(p.0))]
+struct FromPlugin(u32);
+
+// dummy main
+fn main() {}
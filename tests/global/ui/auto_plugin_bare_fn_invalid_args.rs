@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+struct TestPlugin;
+
+#[auto_plugin(plugn = TestPlugin)]
+fn plugin(_app: &mut App) {}
+
+// dummy main
+fn main() {
+    // still defined despite the error above
+    App::new().add_plugins(plugin);
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+impl TestPlugin {
+    #[auto_plugin]
+    fn setup(&self, app: &mut App) {
+        app.init_resource::<Time>();
+    }
+}
+
+// dummy main
+fn main() {
+    // still defined despite the error above
+    TestPlugin.setup(&mut App::new());
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_register_type(plugin = TestPlugin, generics(16, u8))]
+#[derive(Reflect)]
+struct Foo<T, const N: usize>([T; N]);
+
+#[auto_register_type(plugin = TestPlugin, generics('static, 'static, u8))]
+#[derive(Reflect)]
+struct Bar<'a, T>(#[reflect(ignore)] std::marker::PhantomData<&'a T>);
+
+// dummy main
+fn main() {}
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_register_type(plugin = TestPlugin, generics_product(A = [u8, u16]))]
+#[derive(Reflect)]
+struct Foo<A, B>(A, B);
+
+// `Foo` is still emitted, so only the attribute error is reported
+fn uses_foo(_: Foo<u8, bool>) {}
+
+// dummy main
+fn main() {}
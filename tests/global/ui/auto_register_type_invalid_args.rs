@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_register_type(plugin = TestPlugin, phsae = build, grup = "a")]
+#[derive(Reflect)]
+struct Foo;
+
+// `Foo` is still emitted, so only the attribute errors are reported
+fn uses_foo(_: Foo) {}
+
+// dummy main
+fn main() {}
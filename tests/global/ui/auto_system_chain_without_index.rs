@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_system(plugin = TestPlugin, schedule = Update, chain = "movement")]
+fn apply_velocity() {}
+
+#[auto_system(plugin = TestPlugin, schedule = Update, chain_index = 1)]
+fn apply_gravity() {}
+
+// dummy main
+fn main() {}
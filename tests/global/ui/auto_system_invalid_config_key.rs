@@ -0,0 +1,15 @@
+use bevy_auto_plugin::modes::global::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_system(plugin = TestPlugin, schedule = Update, config(run_iff = always))]
+fn my_system() {}
+
+fn always() -> bool {
+    true
+}
+
+// dummy main
+fn main() {}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::modes::global::prelude::*;
+use std::marker::PhantomData;
+
+#[derive(AutoPlugin, Default)]
+#[auto_plugin(impl_generic_plugin_trait, impl_generic_auto_plugin_trait)]
+struct TestPlugin<T: Send + Sync + 'static>(PhantomData<T>);
+
+#[derive(Resource, Default)]
+#[auto_init_resource(plugin = TestPlugin::<T>, generics(T))]
+struct Counter<T: Default + Send + Sync + 'static>(T);
+
+// dummy main
+fn main() {}